@@ -1,172 +1,436 @@
 // @generated by build.rs — do not edit
+#![cfg_attr(rustfmt, rustfmt::skip)]
 
-use aoc_runner::Solution;
-use libsql::Builder;
+use aoc_runner::{
+    DbConfig, INPUT_KEY_ENV, INPUT_KEY_FILE_ENV, InputKey, Params, Solution,
+};
+use aoc_runner::{SolveError, decrypt_input};
 use std::sync::LazyLock;
 fn main() {
+    if cfg!(aoc_memory_db) {
+        eprintln!("{} is :memory:, which holds no inputs to bench", aoc_runner::DB_ENV);
+    }
     divan::main();
 }
+#[allow(dead_code)]
 struct Input {
+    profile: String,
     data: String,
-    solve: Box<dyn Fn(&str) -> String + Send + Sync>,
+    params: Params,
+    solution: &'static dyn Solution,
 }
 static INPUTS: LazyLock<Vec<(u16, u8, u8, Input)>> = LazyLock::new(|| {
     let rt = tokio::runtime::Runtime::new().unwrap();
-    let db_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc.db");
-    let db = rt.block_on(Builder::new_local(db_path).build()).unwrap();
+    let db = rt.block_on(DbConfig::resolve(None).unwrap().open()).unwrap();
     let conn = db.connect().unwrap();
-    inventory::iter::<&dyn Solution>
-        .into_iter()
-        .filter_map(|solution| {
-            let year = solution.year();
-            let day = solution.day();
-            let part = solution.part();
-            let mut rows = rt
-                .block_on(
-                    conn
-                        .query(
-                            "SELECT input FROM solutions WHERE year = ? AND day = ? AND part = ?",
-                            (year, day, part),
-                        ),
-                )
-                .ok()?;
-            let row = rt.block_on(rows.next()).ok()??;
-            let input: String = row.get(0).ok()?;
+    rt.block_on(aoc_runner::migrate(&conn)).unwrap();
+    let key = InputKey::load().unwrap_or_else(|e| panic!("{e}"));
+    let mut inputs = Vec::new();
+    let mut locked: Vec<(u16, u8, String)> = Vec::new();
+    for solution in inventory::iter::<&dyn Solution> {
+        let year = solution.year();
+        let day = solution.day();
+        let part = solution.part();
+        let Ok(mut rows) = rt
+            .block_on(
+                conn
+                    .query(
+                        "SELECT profile, input, params FROM inputs
+                     WHERE year = ? AND day = ? ORDER BY profile",
+                        (year, day),
+                    ),
+            ) else {
+            continue;
+        };
+        while let Ok(Some(row)) = rt.block_on(rows.next()) {
+            let profile: String = row.get(0).unwrap();
+            let input: String = row.get(1).unwrap();
             if input.is_empty() {
-                return None;
+                continue;
             }
-            let s: &'static &'static dyn Solution = solution;
-            Some((
-                year,
-                day,
-                part,
-                Input {
-                    data: input,
-                    solve: Box::new(move |input: &str| s.solve(input)),
-                },
-            ))
+            let params: String = row.get(2).unwrap();
+            let Some(input) = decrypt_input(&input, key.as_ref())
+                .unwrap_or_else(|e| panic!("{year}-{day:02}, profile {profile:?}: {e}"))
+            else {
+                if !locked.contains(&(year, day, profile.clone())) {
+                    locked.push((year, day, profile));
+                }
+                continue;
+            };
+            inputs
+                .push((
+                    year,
+                    day,
+                    part,
+                    Input {
+                        profile,
+                        data: input,
+                        params: params.parse().unwrap(),
+                        solution: *solution,
+                    },
+                ));
+        }
+    }
+    for (year, day, profile) in locked {
+        eprintln!(
+            "skipping {year}-{day:02}, profile {profile:?}: \
+                     the input is encrypted, set {} or {}",
+            INPUT_KEY_ENV, INPUT_KEY_FILE_ENV,
+        );
+    }
+    inputs
+});
+/// Profiles with an input for the puzzle, one bench run each.
+#[allow(dead_code)]
+fn profiles(year: u16, day: u8, part: u8, variant: Option<&str>) -> Vec<&'static str> {
+    INPUTS
+        .iter()
+        .filter(|(y, d, p, i)| {
+            *y == year && *d == day && *p == part && i.solution.variant() == variant
         })
+        .map(|(_, _, _, i)| i.profile.as_str())
         .collect()
-});
-fn find_input(year: u16, day: u8, part: u8) -> &'static Input {
+}
+#[allow(dead_code)]
+fn find_input(
+    year: u16,
+    day: u8,
+    part: u8,
+    variant: Option<&str>,
+    profile: &str,
+) -> &'static Input {
     &INPUTS
         .iter()
-        .find(|(y, d, p, _)| *y == year && *d == day && *p == part)
-        .unwrap_or_else(|| panic!("no input for {year}-{day:02}-{part}"))
+        .find(|(y, d, p, i)| {
+            *y == year && *d == day && *p == part && i.solution.variant() == variant
+                && i.profile == profile
+        })
+        .unwrap_or_else(|| {
+            panic!(
+                "no input for {year}-{day:02}-{part} {variant:?}, profile {profile:?}"
+            )
+        })
         .3
 }
-#[divan::bench]
-fn y2025_d01_p1(bencher: divan::Bencher) {
-    let input = find_input(2025, 1, 1);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d01_p2(bencher: divan::Bencher) {
-    let input = find_input(2025, 1, 2);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d02_p1(bencher: divan::Bencher) {
-    let input = find_input(2025, 2, 1);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d02_p2(bencher: divan::Bencher) {
-    let input = find_input(2025, 2, 2);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d03_p1(bencher: divan::Bencher) {
-    let input = find_input(2025, 3, 1);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d03_p2(bencher: divan::Bencher) {
-    let input = find_input(2025, 3, 2);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d04_p1(bencher: divan::Bencher) {
-    let input = find_input(2025, 4, 1);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d04_p2(bencher: divan::Bencher) {
-    let input = find_input(2025, 4, 2);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d05_p1(bencher: divan::Bencher) {
-    let input = find_input(2025, 5, 1);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d05_p2(bencher: divan::Bencher) {
-    let input = find_input(2025, 5, 2);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d06_p1(bencher: divan::Bencher) {
-    let input = find_input(2025, 6, 1);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d06_p2(bencher: divan::Bencher) {
-    let input = find_input(2025, 6, 2);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d07_p1(bencher: divan::Bencher) {
-    let input = find_input(2025, 7, 1);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d07_p2(bencher: divan::Bencher) {
-    let input = find_input(2025, 7, 2);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d08_p1(bencher: divan::Bencher) {
-    let input = find_input(2025, 8, 1);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d08_p2(bencher: divan::Bencher) {
-    let input = find_input(2025, 8, 2);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d09_p1(bencher: divan::Bencher) {
-    let input = find_input(2025, 9, 1);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d09_p2(bencher: divan::Bencher) {
-    let input = find_input(2025, 9, 2);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d10_p1(bencher: divan::Bencher) {
-    let input = find_input(2025, 10, 1);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d10_p2(bencher: divan::Bencher) {
-    let input = find_input(2025, 10, 2);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d11_p1(bencher: divan::Bencher) {
-    let input = find_input(2025, 11, 1);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d11_p2(bencher: divan::Bencher) {
-    let input = find_input(2025, 11, 2);
-    bencher.bench_local(|| (input.solve)(&input.data));
-}
-#[divan::bench]
-fn y2025_d12_p1(bencher: divan::Bencher) {
-    let input = find_input(2025, 12, 1);
-    bencher.bench_local(|| (input.solve)(&input.data));
+/// The result of a first run, or `None` after reporting its error, which leaves the bench
+/// empty rather than aborting the others.
+#[allow(dead_code)]
+fn solved<T>(result: Result<T, SolveError>, profile: &str) -> Option<T> {
+    result.map_err(|e| eprintln!("error: {e}, profile {profile:?}")).ok()
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 1, 1, None))]
+fn y2025_d01_p1(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 1, 1, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 1, 2, None))]
+fn y2025_d01_p2(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 1, 2, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 1, 2, Some("naive")))]
+fn y2025_d01_p2_naive(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 1, 2, Some("naive"), profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 2, 1, None))]
+fn y2025_d02_p1(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 2, 1, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 2, 2, None))]
+fn y2025_d02_p2(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 2, 2, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 3, 1, None))]
+fn y2025_d03_p1(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 3, 1, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 3, 2, None))]
+fn y2025_d03_p2(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 3, 2, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 4, 1, None))]
+fn y2025_d04_p1(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 4, 1, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 4, 1, None))]
+fn y2025_d04_parse(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 4, 1, None, profile);
+    let staged = input.solution.staged().unwrap();
+    if solved(staged.try_parse(&input.data), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(|| staged.try_parse(&input.data));
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 4, 1, None))]
+fn y2025_d04_p1_solve(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 4, 1, None, profile);
+    let staged = input.solution.staged().unwrap();
+    let Some(parsed) = solved(staged.try_parse(&input.data), profile) else {
+        return;
+    };
+    let solve = || staged.try_solve_parsed(&parsed, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 4, 2, None))]
+fn y2025_d04_p2(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 4, 2, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 4, 2, None))]
+fn y2025_d04_p2_solve(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 4, 2, None, profile);
+    let staged = input.solution.staged().unwrap();
+    let Some(parsed) = solved(staged.try_parse(&input.data), profile) else {
+        return;
+    };
+    let solve = || staged.try_solve_parsed(&parsed, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 5, 1, None))]
+fn y2025_d05_p1(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 5, 1, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 5, 2, None))]
+fn y2025_d05_p2(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 5, 2, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 6, 1, None))]
+fn y2025_d06_p1(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 6, 1, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 6, 2, None))]
+fn y2025_d06_p2(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 6, 2, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 7, 1, None))]
+fn y2025_d07_p1(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 7, 1, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 7, 2, None))]
+fn y2025_d07_p2(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 7, 2, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 8, 1, None))]
+fn y2025_d08_p1(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 8, 1, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 8, 1, None))]
+fn y2025_d08_parse(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 8, 1, None, profile);
+    let staged = input.solution.staged().unwrap();
+    if solved(staged.try_parse(&input.data), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(|| staged.try_parse(&input.data));
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 8, 1, None))]
+fn y2025_d08_p1_solve(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 8, 1, None, profile);
+    let staged = input.solution.staged().unwrap();
+    let Some(parsed) = solved(staged.try_parse(&input.data), profile) else {
+        return;
+    };
+    let solve = || staged.try_solve_parsed(&parsed, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 8, 2, None))]
+fn y2025_d08_p2(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 8, 2, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 8, 2, None))]
+fn y2025_d08_p2_solve(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 8, 2, None, profile);
+    let staged = input.solution.staged().unwrap();
+    let Some(parsed) = solved(staged.try_parse(&input.data), profile) else {
+        return;
+    };
+    let solve = || staged.try_solve_parsed(&parsed, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 9, 1, None))]
+fn y2025_d09_p1(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 9, 1, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 9, 2, None))]
+fn y2025_d09_p2(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 9, 2, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 10, 1, None))]
+fn y2025_d10_p1(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 10, 1, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(all(feature = "y2025", feature = "z3"))]
+#[divan::bench(args = profiles(2025, 10, 2, None))]
+fn y2025_d10_p2(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 10, 2, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(all(feature = "y2025", feature = "petgraph"))]
+#[divan::bench(args = profiles(2025, 11, 1, None))]
+fn y2025_d11_p1(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 11, 1, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(all(feature = "y2025", feature = "pathfinding"))]
+#[divan::bench(args = profiles(2025, 11, 2, None))]
+fn y2025_d11_p2(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 11, 2, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
+}
+#[cfg(feature = "y2025")]
+#[divan::bench(args = profiles(2025, 12, 1, None))]
+fn y2025_d12_p1(bencher: divan::Bencher, profile: &str) {
+    let input = find_input(2025, 12, 1, None, profile);
+    let solve = || input.solution.try_solve_with(&input.data, &input.params);
+    if solved(solve(), profile).is_none() {
+        return;
+    }
+    bencher.bench_local(solve);
 }
@@ -1,196 +1,1182 @@
-use std::fs;
-use std::path::Path;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
 
 use proc_macro2::{Literal, TokenStream};
 use quote::{format_ident, quote};
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Attribute, Item, ItemMod, LitInt, LitStr, Meta, Token};
 
-fn main() {
-    let solutions_file = Path::new("src/solutions.rs");
-
-    println!("cargo:rerun-if-changed=src/solutions.rs");
-
-    let mut entries: Vec<(u16, u8, u8)> = Vec::new();
-
-    let content = fs::read_to_string(solutions_file).unwrap();
-    for line in content.lines() {
-        let line = line.trim();
-        if let Some(rest) = line.strip_prefix("#[solution(") {
-            if let Some(args_str) = rest.strip_suffix(")]") {
-                let args: Vec<&str> = args_str.splitn(3, ',').collect();
-                if args.len() == 3 {
-                    let year: u16 = args[0].trim().parse().unwrap();
-                    let day: u8 = args[1].trim().parse().unwrap();
-                    let part: u8 = args[2].trim().parse().unwrap();
-                    entries.push((year, day, part));
+use crypto::InputKey;
+use db::DbConfig;
+
+#[allow(dead_code)]
+#[path = "src/config.rs"]
+mod config;
+
+#[allow(dead_code)]
+#[path = "src/crypto.rs"]
+mod crypto;
+
+#[allow(dead_code)]
+#[path = "src/db.rs"]
+mod db;
+
+/// `cfg` set to `y{year}_d{day:02}` for the days whose tests are ignored, having an encrypted input
+/// and no key
+const LOCKED_CFG: &str = "aoc_locked_input";
+
+/// `cfg` set when the database is `:memory:`, which starts out empty for every test
+const MEMORY_CFG: &str = "aoc_memory_db";
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Entry {
+    year: u16,
+    day: u8,
+    part: u8,
+    variant: Option<String>,
+    /// Declared in a `#[day]` module, with a parse stage shared between parts
+    staged: bool,
+    /// The `cfg` predicates on the modules around this solution, which gate its generated code
+    cfgs: Vec<String>,
+    /// The `cfg` predicate that compiles this solution out of the current build, if any
+    disabled_by: Option<String>,
+}
+
+impl Entry {
+    /// Suffix appended to generated test and bench names, e.g. `_naive`
+    fn suffix(&self) -> String {
+        self.variant
+            .as_ref()
+            .map(|v| format!("_{v}"))
+            .unwrap_or_default()
+    }
+
+    /// Human-readable name used in assertion messages
+    fn label(&self) -> String {
+        let variant = self
+            .variant
+            .as_ref()
+            .map(|v| format!(" ({v})"))
+            .unwrap_or_default();
+        format!(
+            "year {} day {} part {}{variant}",
+            self.year, self.day, self.part
+        )
+    }
+
+    /// Identity of the solution, ignoring how it was declared
+    fn key(&self) -> (u16, u8, u8, &Option<String>) {
+        (self.year, self.day, self.part, &self.variant)
+    }
+
+    fn variant_tokens(&self) -> TokenStream {
+        match &self.variant {
+            Some(v) => quote! { Some(#v) },
+            None => quote! { None },
+        }
+    }
+
+    /// Whether this solution is compiled in, the same for every build unlike `disabled_by`
+    fn predicate(&self) -> Option<TokenStream> {
+        let cfgs: Vec<TokenStream> = self.cfgs.iter().map(|c| c.parse().unwrap()).collect();
+        match &cfgs[..] {
+            [] => None,
+            [cfg] => Some(cfg.clone()),
+            _ => Some(quote! { all(#(#cfgs),*) }),
+        }
+    }
+}
+
+/// `#[cfg(...)]` for generated code that needs any of `entries`, so that the generated files are
+/// the same whichever features are enabled.
+fn cfg_attr<'a>(entries: impl IntoIterator<Item = &'a Entry>) -> TokenStream {
+    let mut predicates = Vec::new();
+    for entry in entries {
+        match entry.predicate() {
+            Some(predicate) => predicates.push(predicate),
+            None => return TokenStream::new(),
+        }
+    }
+    match &predicates[..] {
+        [] => TokenStream::new(),
+        [predicate] => quote! { #[cfg(#predicate)] },
+        _ => quote! { #[cfg(any(#(#predicates),*))] },
+    }
+}
+
+/// An example stored in the `examples` table, generating one test each
+struct Example {
+    year: u16,
+    day: u8,
+    part: u8,
+    label: String,
+}
+
+/// Reads the examples of the configured database, if it has any, skipping the placeholders of
+/// `new`.
+fn read_examples(config: &DbConfig) -> Vec<Example> {
+    let sql = "SELECT year, day, part, label FROM examples WHERE input != '' AND output != ''
+               ORDER BY year, day, part, label";
+    read_rows(config, "no example tests", sql, |row| Example {
+        year: row.get::<u32>(0).unwrap() as u16,
+        day: row.get::<u32>(1).unwrap() as u8,
+        part: row.get::<u32>(2).unwrap() as u8,
+        label: row.get(3).unwrap(),
+    })
+}
+
+/// The days with an encrypted input, when there is no key to decrypt them with.
+fn read_locked_days(config: &DbConfig) -> Vec<(u16, u8)> {
+    match InputKey::load() {
+        Ok(None) => {}
+        Ok(Some(_)) => return Vec::new(),
+        // The tests report it when they fail to decrypt
+        Err(e) => {
+            println!("cargo:warning=cannot read the input key: {e}");
+            return Vec::new();
+        }
+    }
+    let sql = format!(
+        "SELECT DISTINCT year, day FROM inputs WHERE input LIKE '{}%'",
+        crypto::ENCRYPTED_PREFIX
+    );
+    read_rows(config, "no skips for encrypted inputs", &sql, |row| {
+        (
+            row.get::<u32>(0).unwrap() as u16,
+            row.get::<u32>(1).unwrap() as u8,
+        )
+    })
+}
+
+/// Runs a query on the configured database, returning no rows if it is missing or lacks the table.
+fn read_rows<T>(
+    config: &DbConfig,
+    unavailable: &str,
+    sql: &str,
+    read: impl Fn(&libsql::Row) -> T,
+) -> Vec<T> {
+    if let DbConfig::Local(path) = config
+        && !path.exists()
+    {
+        return Vec::new();
+    }
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    rt.block_on(async {
+        // An unreachable server only costs what the rows were for, not the build
+        let conn = match config.open().await.and_then(|db| db.connect()) {
+            Ok(conn) => conn,
+            Err(e) => {
+                println!("cargo:warning={unavailable}, cannot open {config}: {e}");
+                return Vec::new();
+            }
+        };
+
+        // The tables are missing until `db init` has run on this database
+        let Ok(mut rows) = conn.query(sql, ()).await else {
+            return Vec::new();
+        };
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await.unwrap() {
+            items.push(read(&row));
+        }
+        items
+    })
+}
+
+/// Arguments of a `#[solution(...)]` or `#[day(...)]` attribute that matter to the generated code
+struct Args {
+    numbers: Vec<u64>,
+    variant: Option<String>,
+}
+
+/// Parses `2025, 1, 2, variant = "naive", reference, params(rows = 4)`; `reference` and
+/// `params(...)` only matter to the macro and are checked for shape but otherwise skipped.
+fn parse_args(input: ParseStream) -> syn::Result<Args> {
+    let mut numbers = Vec::new();
+    let mut variant = None;
+
+    while !input.is_empty() {
+        if input.peek(LitInt) {
+            numbers.push(input.parse::<LitInt>()?.base10_parse()?);
+        } else {
+            let ident: syn::Ident = input.parse()?;
+            match ident.to_string().as_str() {
+                "variant" => {
+                    if variant.is_some() {
+                        return Err(syn::Error::new(ident.span(), "duplicate `variant`"));
+                    }
+                    input.parse::<Token![=]>()?;
+                    variant = Some(input.parse::<LitStr>()?.value());
+                }
+                "reference" => {}
+                "params" => {
+                    let content;
+                    syn::parenthesized!(content in input);
+                    content.parse::<TokenStream>()?;
+                }
+                other => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!("unknown option `{other}`"),
+                    ));
+                }
+            }
+        }
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+
+    Ok(Args { numbers, variant })
+}
+
+/// Evaluates a `cfg` predicate against what cargo tells the build script about the build.
+fn cfg_enabled(meta: &Meta) -> syn::Result<bool> {
+    let var = |prefix: &str, name: &str| {
+        env::var(format!("{prefix}{}", name.to_uppercase().replace('-', "_")))
+    };
+    let name = |path: &syn::Path| path.get_ident().map(|i| i.to_string()).unwrap_or_default();
+
+    match meta {
+        Meta::Path(path) => Ok(var("CARGO_CFG_", &name(path)).is_ok()),
+        Meta::NameValue(nv) => {
+            let syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(value),
+                ..
+            }) = &nv.value
+            else {
+                return Err(syn::Error::new_spanned(
+                    &nv.value,
+                    "expected a string literal",
+                ));
+            };
+            if nv.path.is_ident("feature") {
+                return Ok(var("CARGO_FEATURE_", &value.value()).is_ok());
+            }
+            // Multi-valued cfgs such as `target_feature` arrive comma-separated
+            Ok(var("CARGO_CFG_", &name(&nv.path))
+                .is_ok_and(|v| v.split(',').any(|v| v == value.value())))
+        }
+        Meta::List(list) => {
+            let nested = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+            let results = nested
+                .iter()
+                .map(cfg_enabled)
+                .collect::<syn::Result<Vec<_>>>()?;
+            match list.path.get_ident().map(|i| i.to_string()).as_deref() {
+                Some("all") => Ok(results.iter().all(|&r| r)),
+                Some("any") => Ok(results.iter().any(|&r| r)),
+                Some("not") if results.len() == 1 => Ok(!results[0]),
+                _ => Err(syn::Error::new_spanned(
+                    list,
+                    "expected `all(...)`, `any(...)` or `not(...)`",
+                )),
+            }
+        }
+    }
+}
+
+/// The `cfg`s of the modules enclosing the items being scanned
+#[derive(Clone, Default)]
+struct Scope {
+    cfgs: Vec<String>,
+    /// The first of `cfgs` that is false in this build
+    disabled_by: Option<String>,
+}
+
+/// Walks the crate's module tree from `src/lib.rs`, collecting every `#[solution]` and `#[day]`.
+struct Scanner {
+    entries: Vec<Entry>,
+    /// Where each entry was declared, to point duplicates at both sites
+    sites: Vec<String>,
+    errors: Vec<String>,
+}
+
+impl Scanner {
+    fn scan_file(&mut self, path: &Path, dir: &Path, scope: &Scope) {
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) => return self.errors.push(format!("{}: {e}", path.display())),
+        };
+        match syn::parse_file(&content) {
+            Ok(file) => self.scan_items(&file.items, path, dir, scope),
+            Err(e) => self.error(path, e),
+        }
+    }
+
+    /// `dir` is where the out-of-line children of these items live. Modules under a false `cfg`
+    /// are still walked, so that `run` can report what the current features leave out.
+    fn scan_items(&mut self, items: &[Item], path: &Path, dir: &Path, scope: &Scope) {
+        for item in items {
+            let Item::Mod(module) = item else { continue };
+
+            let mut scope = scope.clone();
+            for attr in module.attrs.iter().filter(|a| a.path().is_ident("cfg")) {
+                match attr
+                    .parse_args::<Meta>()
+                    .and_then(|meta| Ok((cfg_enabled(&meta)?, meta)))
+                {
+                    Ok((enabled, meta)) => {
+                        let predicate = quote!(#meta).to_string();
+                        if !enabled && scope.disabled_by.is_none() {
+                            scope.disabled_by = Some(predicate.clone());
+                        }
+                        scope.cfgs.push(predicate);
+                    }
+                    Err(e) => self.error(path, e),
+                }
+            }
+            let scope = &scope;
+
+            for attr in &module.attrs {
+                let Some(name) = attr.path().segments.last().map(|s| s.ident.to_string()) else {
+                    continue;
+                };
+                match name.as_str() {
+                    "solution" => self.solution(attr, path, scope),
+                    "day" => self.day(attr, module, path, scope),
+                    _ => {}
                 }
             }
+
+            let child_dir = dir.join(module.ident.to_string());
+            match &module.content {
+                Some((_, items)) => self.scan_items(items, path, &child_dir, scope),
+                None => match Self::module_file(module, dir) {
+                    Some((file, file_dir)) => self.scan_file(&file, &file_dir, scope),
+                    None => self.error(
+                        path,
+                        syn::Error::new(
+                            module.ident.span(),
+                            format!("file not found for module `{}`", module.ident),
+                        ),
+                    ),
+                },
+            }
         }
     }
 
+    /// Resolves `mod name;` to `name.rs` or `name/mod.rs`, honouring `#[path = "..."]`.
+    fn module_file(module: &ItemMod, dir: &Path) -> Option<(PathBuf, PathBuf)> {
+        let name = module.ident.to_string();
+        for attr in &module.attrs {
+            if attr.path().is_ident("path")
+                && let syn::Meta::NameValue(nv) = &attr.meta
+                && let syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(s),
+                    ..
+                }) = &nv.value
+            {
+                let file = dir.join(s.value());
+                let file_dir = file.parent()?.to_path_buf();
+                return file.exists().then_some((file, file_dir));
+            }
+        }
+
+        let file = dir.join(format!("{name}.rs"));
+        if file.exists() {
+            return Some((file, dir.join(name)));
+        }
+        let file = dir.join(&name).join("mod.rs");
+        file.exists().then(|| (file, dir.join(name)))
+    }
+
+    fn solution(&mut self, attr: &Attribute, path: &Path, scope: &Scope) {
+        let args = match attr.parse_args_with(parse_args) {
+            Ok(args) => args,
+            Err(e) => return self.error(path, e),
+        };
+        let [year, day, part] = args.numbers[..] else {
+            return self.error(
+                path,
+                syn::Error::new_spanned(attr, "expected `#[solution(year, day, part, ...)]`"),
+            );
+        };
+        let (day, part, variant) = (day as u8, part as u8, args.variant);
+        let entry = Entry {
+            year: year as u16,
+            day,
+            part,
+            variant,
+            staged: false,
+            cfgs: scope.cfgs.clone(),
+            disabled_by: scope.disabled_by.clone(),
+        };
+        self.push(entry, path, attr);
+    }
+
+    fn day(&mut self, attr: &Attribute, module: &ItemMod, path: &Path, scope: &Scope) {
+        let args = match attr.parse_args_with(parse_args) {
+            Ok(args) => args,
+            Err(e) => return self.error(path, e),
+        };
+        let [year, day] = args.numbers[..] else {
+            return self.error(
+                path,
+                syn::Error::new_spanned(attr, "expected `#[day(year, day, ...)]`"),
+            );
+        };
+        let Some((_, items)) = &module.content else {
+            return self.error(
+                path,
+                syn::Error::new_spanned(attr, "`#[day]` needs an inline module"),
+            );
+        };
+
+        for (part, name) in [(1, "part1"), (2, "part2")] {
+            let declared = items
+                .iter()
+                .any(|item| matches!(item, Item::Fn(f) if f.sig.ident == name));
+            if declared {
+                let entry = Entry {
+                    year: year as u16,
+                    day: day as u8,
+                    part,
+                    variant: args.variant.clone(),
+                    staged: true,
+                    cfgs: scope.cfgs.clone(),
+                    disabled_by: scope.disabled_by.clone(),
+                };
+                self.push(entry, path, attr);
+            }
+        }
+    }
+
+    fn push(&mut self, entry: Entry, path: &Path, attr: &Attribute) {
+        let site = Self::site(path, attr.span());
+        if let Some(i) = self.entries.iter().position(|e| e.key() == entry.key()) {
+            self.errors.push(format!(
+                "{site}: duplicate {}, first declared at {}",
+                entry.label(),
+                self.sites[i]
+            ));
+            return;
+        }
+        self.entries.push(entry);
+        self.sites.push(site);
+    }
+
+    fn error(&mut self, path: &Path, error: syn::Error) {
+        let site = Self::site(path, error.span());
+        self.errors.push(format!("{site}: {error}"));
+    }
+
+    fn site(path: &Path, span: proc_macro2::Span) -> String {
+        let start = span.start();
+        format!("{}:{}:{}", path.display(), start.line, start.column + 1)
+    }
+}
+
+fn main() {
+    let db = DbConfig::resolve(None).unwrap_or_else(|e| panic!("{e}"));
+
+    println!("cargo:rerun-if-env-changed={}", db::DB_ENV);
+    println!("cargo:rerun-if-env-changed={}", db::AUTH_TOKEN_ENV);
+    // A missing path would rerun the build script on every build
+    if Path::new(config::CONFIG_FILE).exists() {
+        println!("cargo:rerun-if-changed={}", config::CONFIG_FILE);
+    }
+    if let DbConfig::Local(path) = &db {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+    println!("cargo:rerun-if-env-changed={}", crypto::INPUT_KEY_ENV);
+    println!("cargo:rerun-if-env-changed={}", crypto::INPUT_KEY_FILE_ENV);
+    // Even while missing, so that adding the key unlocks the tests of the encrypted inputs
+    println!("cargo:rerun-if-changed={}", crypto::key_path().display());
+
+    // The features of this build, for `watch` to build with again; cargo passes them uppercase, and
+    // those of this crate are lowercase without dashes
+    let mut features: Vec<String> = std::env::vars()
+        .filter_map(|(name, _)| Some(name.strip_prefix("CARGO_FEATURE_")?.to_lowercase()))
+        .collect();
+    features.sort();
+    println!("cargo:rustc-env=AOC_FEATURES={}", features.join(","));
+
+    println!("cargo:rustc-check-cfg=cfg({LOCKED_CFG}, values(any()))");
+    for (year, day) in read_locked_days(&db) {
+        println!("cargo:rustc-cfg={LOCKED_CFG}=\"y{year}_d{day:02}\"");
+    }
+    println!("cargo:rustc-check-cfg=cfg({MEMORY_CFG})");
+    if db == DbConfig::Memory {
+        println!("cargo:rustc-cfg={MEMORY_CFG}");
+        println!("cargo:warning=the database is :memory:, so the tests are ignored");
+    }
+
+    let mut scanner = Scanner {
+        entries: Vec::new(),
+        sites: Vec::new(),
+        errors: Vec::new(),
+    };
+    scanner.scan_file(Path::new("src/lib.rs"), Path::new("src"), &Scope::default());
+
+    if !scanner.errors.is_empty() {
+        panic!(
+            "invalid solution attributes:\n{}",
+            scanner.errors.join("\n")
+        );
+    }
+
+    let mut entries = scanner.entries;
     entries.sort();
 
+    // Every entry, compiled out or not, so that the generated files do not depend on the features
     generate_benches(&entries);
-    generate_tests(&entries);
+    generate_tests(&entries, &read_examples(&db));
+    let compiled_out: Vec<_> = entries.iter().filter(|e| e.disabled_by.is_some()).collect();
+    generate_compiled_out(&compiled_out);
 }
 
-fn generate_benches(entries: &[(u16, u8, u8)]) {
-    let bench_fns: Vec<TokenStream> = entries
-        .iter()
-        .map(|(year, day, part)| {
-            let fn_name = format_ident!("y{}_d{:02}_p{}", year, day, part);
-            let year_lit = Literal::u16_unsuffixed(*year);
-            let day_lit = Literal::u8_unsuffixed(*day);
-            let part_lit = Literal::u8_unsuffixed(*part);
+/// Lists the solutions left out of this build for `aoc_runner::COMPILED_OUT`.
+fn generate_compiled_out(entries: &[&Entry]) {
+    let items = entries.iter().map(|entry| {
+        let year_lit = Literal::u16_unsuffixed(entry.year);
+        let day_lit = Literal::u8_unsuffixed(entry.day);
+        let part_lit = Literal::u8_unsuffixed(entry.part);
+        let variant = entry.variant_tokens();
+        let cfg = entry.disabled_by.as_deref().unwrap_or_default();
+        quote! {
+            CompiledOut {
+                year: #year_lit,
+                day: #day_lit,
+                part: #part_lit,
+                variant: #variant,
+                cfg: #cfg,
+            }
+        }
+    });
 
-            quote! {
-                #[divan::bench]
-                fn #fn_name(bencher: divan::Bencher) {
-                    let input = find_input(#year_lit, #day_lit, #part_lit);
-                    bencher.bench_local(|| (input.solve)(&input.data));
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(
+        out_dir.join("compiled_out.rs"),
+        quote! { &[#(#items),*] }.to_string(),
+    )
+    .unwrap();
+}
+
+fn generate_benches(entries: &[Entry]) {
+    let memory_cfg = format_ident!("{MEMORY_CFG}");
+    let mut bench_fns: Vec<TokenStream> = Vec::new();
+    let mut parse_benched: Option<(u16, u8, &Option<String>)> = None;
+
+    for entry in entries {
+        let suffix = entry.suffix();
+        let fn_name = format_ident!(
+            "y{}_d{:02}_p{}{}",
+            entry.year,
+            entry.day,
+            entry.part,
+            suffix
+        );
+        let year_lit = Literal::u16_unsuffixed(entry.year);
+        let day_lit = Literal::u8_unsuffixed(entry.day);
+        let part_lit = Literal::u8_unsuffixed(entry.part);
+        let variant = entry.variant_tokens();
+        let cfg = cfg_attr([entry]);
+
+        bench_fns.push(quote! {
+            #cfg
+            #[divan::bench(args = profiles(#year_lit, #day_lit, #part_lit, #variant))]
+            fn #fn_name(bencher: divan::Bencher, profile: &str) {
+                let input = find_input(#year_lit, #day_lit, #part_lit, #variant, profile);
+                let solve = || input.solution.try_solve_with(&input.data, &input.params);
+                if solved(solve(), profile).is_none() {
+                    return;
                 }
+                bencher.bench_local(solve);
             }
-        })
-        .collect();
+        });
+
+        if !entry.staged {
+            continue;
+        }
+
+        if parse_benched != Some((entry.year, entry.day, &entry.variant)) {
+            parse_benched = Some((entry.year, entry.day, &entry.variant));
+            let parse_fn_name = format_ident!("y{}_d{:02}{}_parse", entry.year, entry.day, suffix);
+
+            bench_fns.push(quote! {
+                #cfg
+                #[divan::bench(args = profiles(#year_lit, #day_lit, #part_lit, #variant))]
+                fn #parse_fn_name(bencher: divan::Bencher, profile: &str) {
+                    let input = find_input(#year_lit, #day_lit, #part_lit, #variant, profile);
+                    let staged = input.solution.staged().unwrap();
+                    if solved(staged.try_parse(&input.data), profile).is_none() {
+                        return;
+                    }
+                    bencher.bench_local(|| staged.try_parse(&input.data));
+                }
+            });
+        }
+
+        let solve_fn_name = format_ident!(
+            "y{}_d{:02}_p{}{}_solve",
+            entry.year,
+            entry.day,
+            entry.part,
+            suffix
+        );
+
+        bench_fns.push(quote! {
+            #cfg
+            #[divan::bench(args = profiles(#year_lit, #day_lit, #part_lit, #variant))]
+            fn #solve_fn_name(bencher: divan::Bencher, profile: &str) {
+                let input = find_input(#year_lit, #day_lit, #part_lit, #variant, profile);
+                let staged = input.solution.staged().unwrap();
+                let Some(parsed) = solved(staged.try_parse(&input.data), profile) else {
+                    return;
+                };
+                let solve = || staged.try_solve_parsed(&parsed, &input.params);
+                if solved(solve(), profile).is_none() {
+                    return;
+                }
+                bencher.bench_local(solve);
+            }
+        });
+    }
 
     let code = quote! {
-        use aoc_runner::Solution;
-        use libsql::Builder;
+        use aoc_runner::{DbConfig, INPUT_KEY_ENV, INPUT_KEY_FILE_ENV, InputKey, Params, Solution};
+        use aoc_runner::{SolveError, decrypt_input};
         use std::sync::LazyLock;
 
         fn main() {
+            if cfg!(#memory_cfg) {
+                eprintln!("{} is :memory:, which holds no inputs to bench", aoc_runner::DB_ENV);
+            }
             divan::main();
         }
 
+        #[allow(dead_code)]
         struct Input {
+            profile: String,
             data: String,
-            solve: Box<dyn Fn(&str) -> String + Send + Sync>,
+            params: Params,
+            solution: &'static dyn Solution,
         }
 
         static INPUTS: LazyLock<Vec<(u16, u8, u8, Input)>> = LazyLock::new(|| {
             let rt = tokio::runtime::Runtime::new().unwrap();
-            let db_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc.db");
-            let db = rt.block_on(Builder::new_local(db_path).build()).unwrap();
+            let db = rt.block_on(DbConfig::resolve(None).unwrap().open()).unwrap();
             let conn = db.connect().unwrap();
+            rt.block_on(aoc_runner::migrate(&conn)).unwrap();
+            let key = InputKey::load().unwrap_or_else(|e| panic!("{e}"));
 
-            inventory::iter::<&dyn Solution>
-                .into_iter()
-                .filter_map(|solution| {
-                    let year = solution.year();
-                    let day = solution.day();
-                    let part = solution.part();
-
-                    let mut rows = rt.block_on(conn.query(
-                        "SELECT input FROM solutions WHERE year = ? AND day = ? AND part = ?",
-                        (year, day, part),
-                    )).ok()?;
-
-                    let row = rt.block_on(rows.next()).ok()??;
-                    let input: String = row.get(0).ok()?;
-                    if input.is_empty() { return None; }
-
-                    let s: &'static &'static dyn Solution = solution;
-                    Some((year, day, part, Input {
+            let mut inputs = Vec::new();
+            let mut locked: Vec<(u16, u8, String)> = Vec::new();
+            for solution in inventory::iter::<&dyn Solution> {
+                let year = solution.year();
+                let day = solution.day();
+                let part = solution.part();
+
+                let Ok(mut rows) = rt.block_on(conn.query(
+                    "SELECT profile, input, params FROM inputs
+                     WHERE year = ? AND day = ? ORDER BY profile",
+                    (year, day),
+                )) else {
+                    continue;
+                };
+
+                while let Ok(Some(row)) = rt.block_on(rows.next()) {
+                    let profile: String = row.get(0).unwrap();
+                    let input: String = row.get(1).unwrap();
+                    if input.is_empty() { continue; }
+                    let params: String = row.get(2).unwrap();
+
+                    let Some(input) = decrypt_input(&input, key.as_ref())
+                        .unwrap_or_else(|e| panic!("{year}-{day:02}, profile {profile:?}: {e}"))
+                    else {
+                        if !locked.contains(&(year, day, profile.clone())) {
+                            locked.push((year, day, profile));
+                        }
+                        continue;
+                    };
+
+                    inputs.push((year, day, part, Input {
+                        profile,
                         data: input,
-                        solve: Box::new(move |input: &str| s.solve(input)),
-                    }))
+                        params: params.parse().unwrap(),
+                        solution: *solution,
+                    }));
+                }
+            }
+            for (year, day, profile) in locked {
+                eprintln!(
+                    "skipping {year}-{day:02}, profile {profile:?}: \
+                     the input is encrypted, set {} or {}",
+                    INPUT_KEY_ENV,
+                    INPUT_KEY_FILE_ENV,
+                );
+            }
+            inputs
+        });
+
+        /// Profiles with an input for the puzzle, one bench run each.
+        #[allow(dead_code)]
+        fn profiles(year: u16, day: u8, part: u8, variant: Option<&str>) -> Vec<&'static str> {
+            INPUTS.iter()
+                .filter(|(y, d, p, i)| {
+                    *y == year && *d == day && *p == part && i.solution.variant() == variant
                 })
+                .map(|(_, _, _, i)| i.profile.as_str())
                 .collect()
-        });
+        }
 
-        fn find_input(year: u16, day: u8, part: u8) -> &'static Input {
+        #[allow(dead_code)]
+        fn find_input(
+            year: u16,
+            day: u8,
+            part: u8,
+            variant: Option<&str>,
+            profile: &str,
+        ) -> &'static Input {
             &INPUTS.iter()
-                .find(|(y, d, p, _)| *y == year && *d == day && *p == part)
-                .unwrap_or_else(|| panic!("no input for {year}-{day:02}-{part}"))
+                .find(|(y, d, p, i)| {
+                    *y == year
+                        && *d == day
+                        && *p == part
+                        && i.solution.variant() == variant
+                        && i.profile == profile
+                })
+                .unwrap_or_else(|| {
+                    panic!("no input for {year}-{day:02}-{part} {variant:?}, profile {profile:?}")
+                })
                 .3
         }
 
+        /// The result of a first run, or `None` after reporting its error, which leaves the bench
+        /// empty rather than aborting the others.
+        #[allow(dead_code)]
+        fn solved<T>(result: Result<T, SolveError>, profile: &str) -> Option<T> {
+            result
+                .map_err(|e| eprintln!("error: {e}, profile {profile:?}"))
+                .ok()
+        }
+
         #(#bench_fns)*
     };
 
     write_generated(Path::new("benches/bench.rs"), code);
 }
 
-fn generate_tests(entries: &[(u16, u8, u8)]) {
-    let test_fns: Vec<TokenStream> = entries
+/// Ignores a test of the day when its input is encrypted and there is no key, as set by `main`.
+fn locked_attr(year: u16, day: u8) -> TokenStream {
+    let cfg = format_ident!("{LOCKED_CFG}");
+    let lock = format!("y{year}_d{day:02}");
+    let reason = format!(
+        "encrypted input, set {} or {}",
+        crypto::INPUT_KEY_ENV,
+        crypto::INPUT_KEY_FILE_ENV
+    );
+    quote! { #[cfg_attr(#cfg = #lock, ignore = #reason)] }
+}
+
+/// Ignores a test when the database is `:memory:`, which holds no inputs, as set by `main`.
+fn memory_attr() -> TokenStream {
+    let cfg = format_ident!("{MEMORY_CFG}");
+    let reason = format!("{} is :memory:, which holds no inputs", db::DB_ENV);
+    quote! { #[cfg_attr(#cfg, ignore = #reason)] }
+}
+
+fn generate_tests(entries: &[Entry], examples: &[Example]) {
+    let mut test_fns: Vec<TokenStream> = entries
         .iter()
-        .map(|(year, day, part)| {
-            let fn_name = format_ident!("y{}_d{:02}_p{}", year, day, part);
-            let year_lit = Literal::u16_unsuffixed(*year);
-            let day_lit = Literal::u8_unsuffixed(*day);
-            let part_lit = Literal::u8_unsuffixed(*part);
+        .map(|entry| {
+            let fn_name = format_ident!(
+                "y{}_d{:02}_p{}{}",
+                entry.year,
+                entry.day,
+                entry.part,
+                entry.suffix()
+            );
+            let name = fn_name.to_string();
+            let year_lit = Literal::u16_unsuffixed(entry.year);
+            let day_lit = Literal::u8_unsuffixed(entry.day);
+            let part_lit = Literal::u8_unsuffixed(entry.part);
+            let variant = entry.variant_tokens();
+            let label = entry.label();
+            let locked = locked_attr(entry.year, entry.day);
+            let memory = memory_attr();
+            let cfg = cfg_attr([entry]);
 
             quote! {
+                #cfg
                 #[test]
+                #locked
+                #memory
                 fn #fn_name() {
-                    let entry = find_entry(#year_lit, #day_lit, #part_lit);
-                    if entry.input.is_empty() || entry.expected.is_empty() {
-                        return;
-                    }
-                    let actual = (entry.solve)(&entry.input);
-                    assert_eq!(
-                        actual.trim(),
-                        entry.expected.trim(),
-                        "year {} day {} part {}: expected '{}', got '{}'",
-                        #year_lit, #day_lit, #part_lit,
-                        entry.expected.trim(),
-                        actual.trim(),
-                    );
+                    isolated(#name, || {
+                        for entry in find_entries(#year_lit, #day_lit, #part_lit, #variant) {
+                            let input = input_of(entry);
+                            if input.is_empty() || entry.expected.is_empty() {
+                                continue;
+                            }
+                            let actual = entry
+                                .solution
+                                .try_solve_with(input, &entry.params)
+                                .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                            assert_eq!(
+                                actual.trim(),
+                                entry.expected.trim(),
+                                "{}, profile {:?}: expected '{}', got '{}'",
+                                #label,
+                                entry.profile,
+                                entry.expected.trim(),
+                                actual.trim(),
+                            );
+                        }
+                    });
                 }
             }
         })
         .collect();
 
+    let mut puzzles: Vec<(u16, u8, u8)> = entries.iter().map(|e| (e.year, e.day, e.part)).collect();
+    puzzles.dedup();
+
+    for (year, day, part) in puzzles {
+        let variants: Vec<_> = entries
+            .iter()
+            .filter(|e| (e.year, e.day, e.part) == (year, day, part))
+            .collect();
+        if variants.len() < 2 {
+            continue;
+        }
+        let cfg = cfg_attr(variants);
+
+        let fn_name = format_ident!("y{}_d{:02}_p{}_cross_check", year, day, part);
+        let name = fn_name.to_string();
+        let year_lit = Literal::u16_unsuffixed(year);
+        let day_lit = Literal::u8_unsuffixed(day);
+        let part_lit = Literal::u8_unsuffixed(part);
+        let locked = locked_attr(year, day);
+        let memory = memory_attr();
+
+        test_fns.push(quote! {
+            #cfg
+            #[test]
+            #locked
+            #memory
+            fn #fn_name() {
+                isolated(#name, || cross_check(#year_lit, #day_lit, #part_lit));
+            }
+        });
+    }
+
+    // The examples differ between databases, so their tests stay out of the checked-in file
+    let mut example_fns: Vec<TokenStream> = Vec::new();
+    let mut numbered: Option<((u16, u8, u8), usize)> = None;
+    let mut taken = HashSet::new();
+
+    for example in examples {
+        let puzzle = (example.year, example.day, example.part);
+        let variants: Vec<_> = entries
+            .iter()
+            .filter(|e| (e.year, e.day, e.part) == puzzle)
+            .collect();
+        if variants.is_empty() {
+            continue;
+        }
+        let cfg = cfg_attr(variants);
+
+        let n = match numbered {
+            Some((p, n)) if p == puzzle => n + 1,
+            _ => 1,
+        };
+        numbered = Some((puzzle, n));
+
+        let name: String = example
+            .label
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        let name = if name.is_empty() { n.to_string() } else { name };
+
+        let base = format!(
+            "y{}_d{:02}_p{}_example_{}",
+            example.year, example.day, example.part, name
+        );
+        // Labels such as `a-b` and `a_b` sanitize to the same name
+        let mut name = base.clone();
+        for suffix in 2.. {
+            if taken.insert(name.clone()) {
+                break;
+            }
+            name = format!("{base}_{suffix}");
+        }
+        let fn_name = format_ident!("{name}");
+        let year_lit = Literal::u16_unsuffixed(example.year);
+        let day_lit = Literal::u8_unsuffixed(example.day);
+        let part_lit = Literal::u8_unsuffixed(example.part);
+        let label = &example.label;
+
+        example_fns.push(quote! {
+            #cfg
+            #[test]
+            fn #fn_name() {
+                isolated(#name, || check_example(#year_lit, #day_lit, #part_lit, #label));
+            }
+        });
+    }
+    let examples = quote! { #(#example_fns)* };
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let syntax_tree = syn::parse2(examples).unwrap();
+    fs::write(
+        out_dir.join("examples.rs"),
+        prettyplease::unparse(&syntax_tree),
+    )
+    .unwrap();
+
     let code = quote! {
-        use aoc_runner::Solution;
-        use libsql::Builder;
+        use aoc_runner::{DbConfig, INPUT_KEY_ENV, INPUT_KEY_FILE_ENV, InputKey, Params, Solution};
+        use aoc_runner::decrypt_input;
+        // Unused when the features leave out every solution
+        #[allow(unused_imports)]
+        use aoc_runner::isolated;
         use std::sync::LazyLock;
 
+        /// Number of generated inputs each variant is cross-checked on
+        const CROSS_CHECK_SEEDS: u64 = 200;
+
+        #[allow(dead_code)]
         struct TestEntry {
-            input: String,
+            profile: String,
+            /// `None` when the input is encrypted and there is no key
+            input: Option<String>,
             expected: String,
-            solve: Box<dyn Fn(&str) -> String + Send + Sync>,
+            params: Params,
+            solution: &'static dyn Solution,
         }
 
         static ENTRIES: LazyLock<Vec<(u16, u8, u8, TestEntry)>> = LazyLock::new(|| {
             let rt = tokio::runtime::Runtime::new().unwrap();
-            let db_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc.db");
-            let db = rt.block_on(Builder::new_local(db_path).build()).unwrap();
+            let db = rt.block_on(DbConfig::resolve(None).unwrap().open()).unwrap();
+            let conn = db.connect().unwrap();
+            rt.block_on(aoc_runner::migrate(&conn)).unwrap();
+            let key = InputKey::load().unwrap_or_else(|e| panic!("{e}"));
+
+            let mut entries = Vec::new();
+            for solution in inventory::iter::<&dyn Solution> {
+                let year = solution.year();
+                let day = solution.day();
+                let part = solution.part();
+
+                let Ok(mut rows) = rt.block_on(conn.query(
+                    "SELECT i.profile, i.input, COALESCE(a.output, ''), i.params FROM inputs i
+                     LEFT JOIN answers a
+                       ON a.profile = i.profile AND a.year = i.year AND a.day = i.day AND a.part = ?
+                     WHERE i.year = ? AND i.day = ? ORDER BY i.profile",
+                    (part, year, day),
+                )) else {
+                    continue;
+                };
+
+                while let Ok(Some(row)) = rt.block_on(rows.next()) {
+                    let profile: String = row.get(0).unwrap();
+                    let input: String = row.get(1).unwrap();
+                    let params: String = row.get(3).unwrap();
+
+                    entries.push((year, day, part, TestEntry {
+                        input: decrypt_input(&input, key.as_ref()).unwrap_or_else(|e| {
+                            panic!("{year}-{day:02}, profile {profile:?}: {e}")
+                        }),
+                        profile,
+                        expected: row.get(2).unwrap(),
+                        params: params.parse().unwrap(),
+                        solution: *solution,
+                    }));
+                }
+            }
+            entries
+        });
+
+        struct Example {
+            year: u16,
+            day: u8,
+            part: u8,
+            label: String,
+            input: String,
+            expected: String,
+            params: Params,
+        }
+
+        static EXAMPLES: LazyLock<Vec<Example>> = LazyLock::new(|| {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            let db = rt.block_on(DbConfig::resolve(None).unwrap().open()).unwrap();
             let conn = db.connect().unwrap();
+            rt.block_on(aoc_runner::migrate(&conn)).unwrap();
 
+            let Ok(mut rows) = rt.block_on(conn.query(
+                "SELECT year, day, part, label, input, output, params FROM examples
+                 WHERE input != '' AND output != ''",
+                (),
+            )) else {
+                return Vec::new();
+            };
+
+            let mut examples = Vec::new();
+            while let Ok(Some(row)) = rt.block_on(rows.next()) {
+                examples.push(Example {
+                    year: row.get::<u32>(0).unwrap() as u16,
+                    day: row.get::<u32>(1).unwrap() as u8,
+                    part: row.get::<u32>(2).unwrap() as u8,
+                    label: row.get(3).unwrap(),
+                    input: row.get(4).unwrap(),
+                    expected: row.get(5).unwrap(),
+                    params: row.get::<String>(6).unwrap().parse().unwrap(),
+                });
+            }
+            examples
+        });
+
+        fn variants_of(year: u16, day: u8, part: u8) -> Vec<&'static dyn Solution> {
             inventory::iter::<&dyn Solution>
                 .into_iter()
-                .filter_map(|solution| {
-                    let year = solution.year();
-                    let day = solution.day();
-                    let part = solution.part();
-
-                    let mut rows = rt.block_on(conn.query(
-                        "SELECT input, output FROM solutions WHERE year = ? AND day = ? AND part = ?",
-                        (year, day, part),
-                    )).ok()?;
-
-                    let row = rt.block_on(rows.next()).ok()??;
-                    let input: String = row.get(0).ok()?;
-                    let expected: String = row.get(1).ok()?;
-
-                    let s: &'static &'static dyn Solution = solution;
-                    Some((year, day, part, TestEntry {
-                        input,
-                        expected,
-                        solve: Box::new(move |input: &str| s.solve(input)),
-                    }))
-                })
+                .copied()
+                .filter(|s| s.year() == year && s.day() == day && s.part() == part)
                 .collect()
-        });
+        }
 
-        fn find_entry(year: u16, day: u8, part: u8) -> &'static TestEntry {
-            &ENTRIES.iter()
-                .find(|(y, d, p, _)| *y == year && *d == day && *p == part)
-                .unwrap_or_else(|| panic!("no entry for {year}-{day:02}-{part}"))
-                .3
+        /// Runs every variant of a puzzle on one of its examples.
+        #[allow(dead_code)]
+        fn check_example(year: u16, day: u8, part: u8, label: &str) {
+            let Some(example) = EXAMPLES
+                .iter()
+                .find(|e| e.year == year && e.day == day && e.part == part && e.label == label)
+            else {
+                panic!(
+                    "year {year} day {day} part {part}: no example {label:?} in the database, \
+                     which changed since the tests were generated"
+                );
+            };
+
+            for solution in variants_of(year, day, part) {
+                let actual = solution
+                    .try_solve_with(&example.input, &example.params)
+                    .unwrap_or_else(|e| panic!("{e}"));
+                assert_eq!(
+                    actual.trim(),
+                    example.expected.trim(),
+                    "year {year} day {day} part {part} variant {:?}, example {label:?}: \
+                     expected '{}', got '{}'",
+                    solution.variant().unwrap_or("default"),
+                    example.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        }
+
+        /// The stored inputs of a solution, one per profile.
+        #[allow(dead_code)]
+        fn find_entries(
+            year: u16,
+            day: u8,
+            part: u8,
+            variant: Option<&str>,
+        ) -> Vec<&'static TestEntry> {
+            let entries: Vec<_> = ENTRIES.iter()
+                .filter(|(y, d, p, e)| {
+                    *y == year && *d == day && *p == part && e.solution.variant() == variant
+                })
+                .map(|(_, _, _, e)| e)
+                .collect();
+            if entries.is_empty() {
+                panic!("no entry for {year}-{day:02}-{part} {variant:?}");
+            }
+            entries
+        }
+
+        /// The decrypted input of an entry, which takes the key when it is encrypted.
+        #[allow(dead_code)]
+        fn input_of(entry: &TestEntry) -> &str {
+            entry.input.as_deref().unwrap_or_else(|| {
+                panic!(
+                    "profile {:?}: the input is encrypted, \
+                     set {INPUT_KEY_ENV} or {INPUT_KEY_FILE_ENV}",
+                    entry.profile,
+                )
+            })
+        }
+
+        /// Runs every variant of a puzzle on the stored inputs, the examples and
+        /// generated inputs, and checks that they all agree with the reference variant.
+        #[allow(dead_code)]
+        fn cross_check(year: u16, day: u8, part: u8) {
+            let variants = variants_of(year, day, part);
+
+            let reference = variants
+                .iter()
+                .find(|s| s.is_reference())
+                .or_else(|| variants.iter().find(|s| s.variant().is_none()))
+                .unwrap_or_else(|| panic!("no reference variant for {year}-{day:02}-{part}"));
+
+            let mut inputs: Vec<(String, String, Params)> = ENTRIES
+                .iter()
+                .filter(|(y, d, p, e)| {
+                    *y == year
+                        && *d == day
+                        && *p == part
+                        && e.solution.variant() == reference.variant()
+                })
+                .map(|(_, _, _, e)| {
+                    let label = format!("input of profile {:?}", e.profile);
+                    (label, input_of(e).to_string(), e.params.clone())
+                })
+                .filter(|(_, input, _)| !input.is_empty())
+                .collect();
+            inputs.extend(
+                EXAMPLES
+                    .iter()
+                    .filter(|e| e.year == year && e.day == day && e.part == part)
+                    .map(|e| (format!("example {:?}", e.label), e.input.clone(), e.params.clone())),
+            );
+            inputs.extend((0..CROSS_CHECK_SEEDS).filter_map(|seed| {
+                reference
+                    .generate(seed)
+                    .map(|input| {
+                        (format!("generated input (seed {seed})"), input, Params::default())
+                    })
+            }));
+
+            for (label, input, params) in &inputs {
+                let expected = reference
+                    .try_solve_with(input, params)
+                    .unwrap_or_else(|e| panic!("reference failed on {label}: {e}"));
+                for variant in variants.iter().filter(|s| s.variant() != reference.variant()) {
+                    let actual = variant
+                        .try_solve_with(input, params)
+                        .unwrap_or_else(|e| panic!("{label}: {e}"));
+                    assert_eq!(
+                        actual.trim(),
+                        expected.trim(),
+                        "year {year} day {day} part {part}: \
+                         variant {:?} disagrees with reference {:?} on {label}",
+                        variant.variant().unwrap_or("default"),
+                        reference.variant().unwrap_or("default"),
+                    );
+                }
+            }
         }
 
         #(#test_fns)*
+
+        // The tests of the examples in the configured database, see `generate_tests`
+        include!(concat!(env!("OUT_DIR"), "/examples.rs"));
     };
 
     write_generated(Path::new("tests/verify.rs"), code);
@@ -199,5 +1185,8 @@ fn generate_tests(entries: &[(u16, u8, u8)]) {
 fn write_generated(path: &Path, code: TokenStream) {
     let syntax_tree = syn::parse2(code).unwrap();
     let formatted = prettyplease::unparse(&syntax_tree);
-    fs::write(path, format!("// @generated by build.rs — do not edit\n\n{formatted}")).unwrap();
+    // rustfmt would format it differently from prettyplease, and the next build would undo that
+    let header =
+        "// @generated by build.rs — do not edit\n#![cfg_attr(rustfmt, rustfmt::skip)]\n\n";
+    fs::write(path, format!("{header}{formatted}")).unwrap();
 }
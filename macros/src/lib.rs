@@ -1,11 +1,12 @@
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
-use syn::{parse::Parse, parse_macro_input, LitInt, Token};
+use syn::{LitInt, LitStr, Token, parse::Parse, parse_macro_input};
 
 struct SolutionArgs {
     year: LitInt,
     day: LitInt,
     part: LitInt,
+    options: Options,
 }
 
 impl Parse for SolutionArgs {
@@ -15,7 +16,133 @@ impl Parse for SolutionArgs {
         let day = input.parse()?;
         input.parse::<Token![,]>()?;
         let part = input.parse()?;
-        Ok(SolutionArgs { year, day, part })
+        let options = input.parse()?;
+        Ok(SolutionArgs {
+            year,
+            day,
+            part,
+            options,
+        })
+    }
+}
+
+/// Optional trailing arguments: `variant = "label"`, `reference` and
+/// `params(name = default, ...)`.
+#[derive(Default)]
+struct Options {
+    variant: Option<LitStr>,
+    reference: bool,
+    params: Vec<(syn::Ident, i64)>,
+}
+
+impl Parse for Options {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut options = Options::default();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            let key: syn::Ident = input.parse()?;
+            if key == "reference" {
+                options.reference = true;
+            } else if key == "variant" {
+                input.parse::<Token![=]>()?;
+                let variant: LitStr = input.parse()?;
+                let value = variant.value();
+                if value.is_empty() || !value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                {
+                    return Err(syn::Error::new_spanned(
+                        variant,
+                        "variant must be a non-empty identifier-like label",
+                    ));
+                }
+                options.variant = Some(variant);
+            } else if key == "params" {
+                let content;
+                syn::parenthesized!(content in input);
+                while !content.is_empty() {
+                    let name: syn::Ident = content.parse()?;
+                    content.parse::<Token![=]>()?;
+                    let negative = content.parse::<Option<Token![-]>>()?.is_some();
+                    let value: i64 = content.parse::<LitInt>()?.base10_parse()?;
+                    options
+                        .params
+                        .push((name, if negative { -value } else { value }));
+                    if !content.is_empty() {
+                        content.parse::<Token![,]>()?;
+                    }
+                }
+            } else {
+                return Err(syn::Error::new_spanned(
+                    key,
+                    "expected `variant = \"...\"`, `reference` or `params(...)`",
+                ));
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// `Solution` methods shared by `#[solution]` and `#[day]` derived from the
+/// options and from optional functions of the module. The declared parameters
+/// only belong to a solution whose function `solve` takes them.
+fn option_methods(options: &Options, items: &[syn::Item], solve: &str) -> proc_macro2::TokenStream {
+    let reference = options.reference;
+    let generate = find_fn(items, "generate").map(|_| {
+        quote! {
+            fn generate(&self, seed: u64) -> Option<String> {
+                Some(generate(&mut crate::Rng::new(seed)))
+            }
+        }
+    });
+    let params = match takes_params(items, solve) {
+        true => &options.params[..],
+        false => &[],
+    };
+    let params = params.iter().map(|(name, value)| {
+        let name = name.to_string();
+        quote! { (#name, #value) }
+    });
+    quote! {
+        fn is_reference(&self) -> bool { #reference }
+        fn params(&self) -> &'static [(&'static str, i64)] { &[#(#params),*] }
+        #generate
+    }
+}
+
+/// Resolves the caller's `params` against the declared defaults, binding the
+/// result to `params` for the module's functions.
+fn resolve_params(year: &LitInt, day: &LitInt, part: &LitInt) -> proc_macro2::TokenStream {
+    quote! {
+        #[allow(unused_variables)]
+        let params = &crate::Params::resolve(self, params)
+            .map_err(|e| e.with_puzzle(#year, #day, #part))?;
+    }
+}
+
+/// Arguments to call the module's function `name` with, passing the
+/// parameters only when it declares a second argument for them.
+fn call_args(
+    items: &[syn::Item],
+    name: &str,
+    first: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if takes_params(items, name) {
+        quote! { (#first, params) }
+    } else {
+        quote! { (#first) }
+    }
+}
+
+/// Whether the module's function `name` declares a second argument for the
+/// parameters.
+fn takes_params(items: &[syn::Item], name: &str) -> bool {
+    find_fn(items, name).is_some_and(|f| f.sig.inputs.len() >= 2)
+}
+
+/// The module name suffix and `Solution::variant` body for an optional variant.
+fn variant_tokens(variant: &Option<LitStr>) -> (String, proc_macro2::TokenStream) {
+    match variant {
+        Some(v) => (format!("_{}", v.value()), quote! { Some(#v) }),
+        None => (String::new(), quote! { None }),
     }
 }
 
@@ -32,7 +159,14 @@ pub fn solution(attr: TokenStream, item: TokenStream) -> TokenStream {
     let day_val: u8 = day.base10_parse().unwrap();
     let part_val: u8 = part.base10_parse().unwrap();
 
-    let mod_name = format_ident!("solution_y{}_d{:02}_p{}", year_val, day_val, part_val);
+    let (suffix, variant) = variant_tokens(&args.options.variant);
+    let mod_name = format_ident!(
+        "solution_y{}_d{:02}_p{}{}",
+        year_val,
+        day_val,
+        part_val,
+        suffix
+    );
 
     let content = match body.content {
         Some((_, items)) => items,
@@ -43,6 +177,20 @@ pub fn solution(attr: TokenStream, item: TokenStream) -> TokenStream {
         }
     };
 
+    let options = option_methods(&args.options, &content, "solve");
+    let resolve = resolve_params(year, day, part);
+    let args = call_args(&content, "solve", quote! { input });
+
+    let try_solve = if returns_result(&content, "solve") {
+        quote! {
+            solve #args
+                .map(|answer| answer.to_string())
+                .map_err(|e| Into::<crate::SolveError>::into(e).with_puzzle(#year, #day, #part))
+        }
+    } else {
+        quote! { Ok(solve #args.to_string()) }
+    };
+
     let expanded = quote! {
         mod #mod_name {
             pub struct S;
@@ -51,8 +199,15 @@ pub fn solution(attr: TokenStream, item: TokenStream) -> TokenStream {
                 fn year(&self) -> u16 { #year }
                 fn day(&self) -> u8 { #day }
                 fn part(&self) -> u8 { #part }
-                fn solve(&self, input: &str) -> String {
-                    solve(input).to_string()
+                fn variant(&self) -> Option<&'static str> { #variant }
+                #options
+                fn try_solve_with(
+                    &self,
+                    input: &str,
+                    params: &crate::Params,
+                ) -> Result<String, crate::SolveError> {
+                    #resolve
+                    #try_solve
                 }
             }
 
@@ -64,3 +219,204 @@ pub fn solution(attr: TokenStream, item: TokenStream) -> TokenStream {
 
     expanded.into()
 }
+
+struct DayArgs {
+    year: LitInt,
+    day: LitInt,
+    options: Options,
+}
+
+impl Parse for DayArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let year = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let day = input.parse()?;
+        let options = input.parse()?;
+        Ok(DayArgs { year, day, options })
+    }
+}
+
+#[proc_macro_attribute]
+pub fn day(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as DayArgs);
+    let body = parse_macro_input!(item as syn::ItemMod);
+
+    let year = &args.year;
+    let day = &args.day;
+
+    let year_val: u16 = year.base10_parse().unwrap();
+    let day_val: u8 = day.base10_parse().unwrap();
+
+    let (suffix, variant) = variant_tokens(&args.options.variant);
+    let mod_name = format_ident!("day_y{}_d{:02}{}", year_val, day_val, suffix);
+
+    let content = match body.content {
+        Some((_, items)) => items,
+        None => {
+            return syn::Error::new_spanned(body, "#[day] module must have inline content")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let Some(parse_fn) = find_fn(&content, "parse") else {
+        return syn::Error::new_spanned(
+            &body.ident,
+            "#[day] module must define `fn parse(&str) -> Input`",
+        )
+        .to_compile_error()
+        .into();
+    };
+
+    let parsed_ty = match &parse_fn.sig.output {
+        syn::ReturnType::Type(_, ty) if returns_result(&content, "parse") => {
+            match result_ok_type(ty) {
+                Some(ty) => ty.clone(),
+                None => {
+                    return syn::Error::new_spanned(
+                        ty,
+                        "cannot infer the parsed type from this `Result`",
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+            }
+        }
+        syn::ReturnType::Type(_, ty) => ty.as_ref().clone(),
+        syn::ReturnType::Default => {
+            return syn::Error::new_spanned(&parse_fn.sig, "`parse` must return the parsed input")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let parts: Vec<_> = [(1u8, "part1"), (2u8, "part2")]
+        .into_iter()
+        .filter(|(_, name)| find_fn(&content, name).is_some())
+        .map(|(part, name)| {
+            let struct_name = format_ident!("P{}", part);
+            let fn_name = format_ident!("{}", name);
+            let part = LitInt::new(&part.to_string(), proc_macro2::Span::call_site());
+
+            let parse_call = if returns_result(&content, "parse") {
+                quote! {
+                    parse(input).map_err(|e| {
+                        Into::<crate::SolveError>::into(e).with_puzzle(#year, #day, #part)
+                    })?
+                }
+            } else {
+                quote! { parse(input) }
+            };
+
+            let options = option_methods(&args.options, &content, name);
+            let resolve = resolve_params(year, day, &part);
+            let args = call_args(&content, name, quote! { parsed });
+
+            let solve_call = if returns_result(&content, name) {
+                quote! {
+                    #fn_name #args
+                        .map(|answer| answer.to_string())
+                        .map_err(|e| {
+                            Into::<crate::SolveError>::into(e).with_puzzle(#year, #day, #part)
+                        })
+                }
+            } else {
+                quote! { Ok(#fn_name #args.to_string()) }
+            };
+
+            quote! {
+                pub struct #struct_name;
+
+                impl crate::Solution for #struct_name {
+                    fn year(&self) -> u16 { #year }
+                    fn day(&self) -> u8 { #day }
+                    fn part(&self) -> u8 { #part }
+                    fn variant(&self) -> Option<&'static str> { #variant }
+                    #options
+                    fn try_solve_with(
+                        &self,
+                        input: &str,
+                        params: &crate::Params,
+                    ) -> Result<String, crate::SolveError> {
+                        #resolve
+                        let parsed = &#parse_call;
+                        #solve_call
+                    }
+                    fn staged(&self) -> Option<&dyn crate::Staged> {
+                        Some(self)
+                    }
+                }
+
+                impl crate::Staged for #struct_name {
+                    fn module(&self) -> &'static str { module_path!() }
+                    fn try_parse(&self, input: &str) -> Result<crate::Parsed, crate::SolveError> {
+                        Ok(Box::new(#parse_call))
+                    }
+                    fn try_solve_parsed(
+                        &self,
+                        parsed: &crate::Parsed,
+                        params: &crate::Params,
+                    ) -> Result<String, crate::SolveError> {
+                        #resolve
+                        let parsed = parsed.downcast_ref::<#parsed_ty>().ok_or_else(|| {
+                            crate::SolveError::new("parsed input was produced by another day")
+                                .with_puzzle(#year, #day, #part)
+                        })?;
+                        #solve_call
+                    }
+                }
+
+                inventory::submit!(&#struct_name as &dyn crate::Solution);
+            }
+        })
+        .collect();
+
+    if parts.is_empty() {
+        return syn::Error::new_spanned(
+            &body.ident,
+            "#[day] module must define `part1` and/or `part2`",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let expanded = quote! {
+        mod #mod_name {
+            #(#parts)*
+
+            #(#content)*
+        }
+    };
+
+    expanded.into()
+}
+
+fn find_fn<'a>(items: &'a [syn::Item], name: &str) -> Option<&'a syn::ItemFn> {
+    items.iter().find_map(|item| match item {
+        syn::Item::Fn(f) if f.sig.ident == name => Some(f),
+        _ => None,
+    })
+}
+
+/// Whether the module's function `name` is declared to return a `Result`.
+fn returns_result(items: &[syn::Item], name: &str) -> bool {
+    find_fn(items, name).is_some_and(|f| match &f.sig.output {
+        syn::ReturnType::Type(_, ty) => match ty.as_ref() {
+            syn::Type::Path(p) => p.path.segments.last().is_some_and(|s| s.ident == "Result"),
+            _ => false,
+        },
+        syn::ReturnType::Default => false,
+    })
+}
+
+/// The `T` of a `Result<T, E>` type.
+fn result_ok_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(p) = ty else { return None };
+    let syn::PathArguments::AngleBracketed(args) = &p.path.segments.last()?.arguments else {
+        return None;
+    };
+    args.args.iter().find_map(|arg| match arg {
+        syn::GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    })
+}
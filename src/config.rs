@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Per-checkout config file in the crate root, with `key = value` lines
+pub const CONFIG_FILE: &str = "aoc.conf";
+
+/// The settings of `aoc.conf`, each overridden by its flag or environment variable.
+#[derive(Debug, Default)]
+pub struct ConfigFile {
+    /// `db = ...` and `runs_db = ...`, see [`crate::DbConfig`]
+    pub db: Option<String>,
+    pub runs_db: Option<String>,
+    pub auth_token: Option<String>,
+    /// `base_url = ...`, the site inputs are fetched from
+    pub base_url: Option<String>,
+    /// Session cookies by profile, from `session.<profile> = ...`, with `session = ...` for
+    /// `default`
+    pub sessions: HashMap<String, String>,
+    /// `timeout = ...` and `memory_limit = ...`, see [`crate::Limits`]
+    pub timeout: Option<String>,
+    pub memory_limit: Option<String>,
+}
+
+impl ConfigFile {
+    /// Reads `aoc.conf` from the crate root, empty when there is none.
+    pub fn read() -> Result<Self, String> {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(CONFIG_FILE);
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            return Ok(ConfigFile::default());
+        };
+
+        let mut file = ConfigFile::default();
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!(
+                    "{}:{}: expected `key = value`",
+                    path.display(),
+                    i + 1
+                ));
+            };
+            let value = value.trim().trim_matches('"').to_string();
+            match key.trim() {
+                "db" => file.db = Some(value),
+                "runs_db" => file.runs_db = Some(value),
+                "auth_token" => file.auth_token = Some(value),
+                "base_url" => file.base_url = Some(value),
+                "timeout" => file.timeout = Some(value),
+                "memory_limit" => file.memory_limit = Some(value),
+                "session" => {
+                    file.sessions.insert("default".to_string(), value);
+                }
+                other => match other.strip_prefix("session.") {
+                    Some(profile) => {
+                        file.sessions.insert(profile.to_string(), value);
+                    }
+                    None => {
+                        return Err(format!(
+                            "{}:{}: unknown key '{other}'",
+                            path.display(),
+                            i + 1
+                        ));
+                    }
+                },
+            }
+        }
+        Ok(file)
+    }
+}
@@ -0,0 +1,102 @@
+use std::path::{Path, PathBuf};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use sha2::{Digest, Sha256};
+
+/// Environment variable holding the secret the input key is derived from
+pub const INPUT_KEY_ENV: &str = "AOC_INPUT_KEY";
+
+/// Environment variable naming a file holding the secret
+pub const INPUT_KEY_FILE_ENV: &str = "AOC_INPUT_KEY_FILE";
+
+/// Untracked key file in the crate root, used when neither variable is set
+pub const INPUT_KEY_FILE: &str = "aoc.key";
+
+/// Marks an encrypted input, followed by the base64 of the nonce and the ciphertext
+pub const ENCRYPTED_PREFIX: &str = "aoc-enc1:";
+
+/// Key encrypting the puzzle inputs stored in the database, which AoC asks not to publish.
+pub struct InputKey(ChaCha20Poly1305);
+
+impl InputKey {
+    /// Derives the key from any secret, e.g. a passphrase or random bytes.
+    pub fn from_secret(secret: &str) -> Self {
+        let digest = Sha256::digest(secret.trim().as_bytes());
+        InputKey(ChaCha20Poly1305::new(&digest))
+    }
+
+    /// Reads the secret from, in order: `AOC_INPUT_KEY`, the file named by `AOC_INPUT_KEY_FILE`,
+    /// and `aoc.key` in the crate root. `None` when there is none.
+    pub fn load() -> Result<Option<Self>, String> {
+        if let Ok(secret) = std::env::var(INPUT_KEY_ENV) {
+            return Ok(Some(Self::from_secret(&secret)));
+        }
+        let Some(path) = key_file() else {
+            return Ok(None);
+        };
+        let secret =
+            std::fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok(Some(Self::from_secret(&secret)))
+    }
+
+    pub fn encrypt(&self, input: &str) -> String {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let mut sealed = nonce.to_vec();
+        sealed.extend(
+            self.0
+                .encrypt(&nonce, input.as_bytes())
+                .expect("encryption cannot fail"),
+        );
+        format!("{ENCRYPTED_PREFIX}{}", BASE64.encode(sealed))
+    }
+
+    /// Decrypts a stored input, passing plain ones through.
+    pub fn decrypt(&self, stored: &str) -> Result<String, String> {
+        let Some(encoded) = stored.strip_prefix(ENCRYPTED_PREFIX) else {
+            return Ok(stored.to_string());
+        };
+        let sealed = BASE64
+            .decode(encoded.trim())
+            .map_err(|e| format!("corrupt encrypted input: {e}"))?;
+        if sealed.len() < 12 {
+            return Err("corrupt encrypted input: too short".to_string());
+        }
+        let (nonce, ciphertext) = sealed.split_at(12);
+        let plain = self
+            .0
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| "cannot decrypt input: wrong key or corrupt data".to_string())?;
+        String::from_utf8(plain).map_err(|e| format!("decrypted input is not UTF-8: {e}"))
+    }
+}
+
+/// The key file named by `AOC_INPUT_KEY_FILE`, or else `aoc.key` in the crate root, which may not
+/// exist.
+pub fn key_path() -> PathBuf {
+    match std::env::var(INPUT_KEY_FILE_ENV) {
+        Ok(path) => PathBuf::from(path),
+        Err(_) => Path::new(env!("CARGO_MANIFEST_DIR")).join(INPUT_KEY_FILE),
+    }
+}
+
+/// The key file to read, if any: the one named by `AOC_INPUT_KEY_FILE`, or an existing `aoc.key`.
+pub fn key_file() -> Option<PathBuf> {
+    let path = key_path();
+    (std::env::var_os(INPUT_KEY_FILE_ENV).is_some() || path.exists()).then_some(path)
+}
+
+pub fn is_encrypted(stored: &str) -> bool {
+    stored.starts_with(ENCRYPTED_PREFIX)
+}
+
+/// The plain text of a stored input, or `None` when it is encrypted and there is no key.
+pub fn decrypt_input(stored: &str, key: Option<&InputKey>) -> Result<Option<String>, String> {
+    match key {
+        Some(key) => key.decrypt(stored).map(Some),
+        None if is_encrypted(stored) => Ok(None),
+        None => Ok(Some(stored.to_string())),
+    }
+}
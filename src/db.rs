@@ -0,0 +1,344 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use libsql::{Builder, Connection, Database};
+
+use crate::config::ConfigFile;
+
+/// Environment variable naming the database, as a path, `:memory:` or a remote URL
+pub const DB_ENV: &str = "AOC_DB";
+
+/// Environment variable holding the auth token of a remote database
+pub const AUTH_TOKEN_ENV: &str = "AOC_DB_AUTH_TOKEN";
+
+/// Environment variable naming the database of runs, like [`DB_ENV`]
+pub const RUNS_DB_ENV: &str = "AOC_RUNS_DB";
+
+/// Where the puzzle database lives, shared by the CLI, tests and benches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DbConfig {
+    /// A SQLite file
+    Local(PathBuf),
+    /// A database held in memory; every connection starts out empty
+    Memory,
+    /// A libsql server such as sqld, reached over `libsql://`, `http://` or `https://`
+    Remote { url: String, auth_token: String },
+}
+
+impl DbConfig {
+    /// The crate root, against which the config file and its relative paths are resolved
+    fn root() -> &'static Path {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+    }
+
+    /// Picks the database from, in order: `flag` (the CLI's `--db`), `AOC_DB`,
+    /// the `aoc.conf` file, and finally the checked-in `aoc.db`.
+    ///
+    /// A relative path in `flag` is taken from the current directory, like any other argument,
+    /// and one in `AOC_DB` or `aoc.conf` from the crate root, which is where tests, benches and
+    /// the build script run.
+    pub fn resolve(flag: Option<&str>) -> Result<Self, String> {
+        let file = ConfigFile::read()?;
+        let auth_token = std::env::var(AUTH_TOKEN_ENV).ok().or(file.auth_token);
+
+        if let Some(value) = flag {
+            let cwd = std::env::current_dir()
+                .map_err(|e| format!("cannot resolve the database {value}: {e}"))?;
+            return Ok(Self::from_value(value, &cwd, auth_token));
+        }
+        match std::env::var(DB_ENV).ok().or(file.db) {
+            Some(value) => Ok(Self::from_value(&value, Self::root(), auth_token)),
+            None => Ok(Self::Local(Self::root().join("aoc.db"))),
+        }
+    }
+
+    /// Picks the database of the recorded runs and bench results, which grows with every `run`
+    /// and `bench record` and so is kept out of the checked-in `aoc.db`: `AOC_RUNS_DB`,
+    /// `runs_db` in `aoc.conf`, or the untracked `runs.db` in the crate root.
+    pub fn resolve_runs() -> Result<Self, String> {
+        let file = ConfigFile::read()?;
+        let auth_token = std::env::var(AUTH_TOKEN_ENV).ok().or(file.auth_token);
+        match std::env::var(RUNS_DB_ENV).ok().or(file.runs_db) {
+            Some(value) => Ok(Self::from_value(&value, Self::root(), auth_token)),
+            None => Ok(Self::Local(Self::root().join("runs.db"))),
+        }
+    }
+
+    /// Whether this is the checked-in `aoc.db`, which is public and so must only hold encrypted
+    /// inputs.
+    pub fn is_checked_in(&self) -> bool {
+        let checked_in = Self::root().join("aoc.db");
+        match self {
+            Self::Local(path) => {
+                path == &checked_in
+                    || path.canonicalize().is_ok_and(|path| {
+                        checked_in
+                            .canonicalize()
+                            .is_ok_and(|checked_in| path == checked_in)
+                    })
+            }
+            Self::Memory | Self::Remote { .. } => false,
+        }
+    }
+
+    /// Reads a `:memory:`, a remote URL or a local path, relative to `dir`.
+    fn from_value(value: &str, dir: &Path, auth_token: Option<String>) -> Self {
+        let remote = ["libsql://", "http://", "https://"]
+            .iter()
+            .any(|scheme| value.starts_with(scheme));
+        if value == ":memory:" {
+            Self::Memory
+        } else if remote {
+            Self::Remote {
+                url: value.to_string(),
+                auth_token: auth_token.unwrap_or_default(),
+            }
+        } else {
+            Self::Local(dir.join(value))
+        }
+    }
+
+    pub async fn open(&self) -> libsql::Result<Database> {
+        match self {
+            Self::Local(path) => Builder::new_local(path).build().await,
+            Self::Memory => Builder::new_local(":memory:").build().await,
+            Self::Remote { url, auth_token } => {
+                Builder::new_remote(url.clone(), auth_token.clone())
+                    .build()
+                    .await
+            }
+        }
+    }
+}
+
+impl fmt::Display for DbConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Local(path) => write!(f, "{}", path.display()),
+            Self::Memory => write!(f, ":memory:"),
+            Self::Remote { url, .. } => write!(f, "{url}"),
+        }
+    }
+}
+
+/// A schema change, applied once, in order, and recorded in the `schema_version` table.
+pub struct Migration {
+    pub version: u32,
+    pub name: &'static str,
+    sql: &'static str,
+    /// Query run first, returning a message for each row the migration cannot carry over
+    check: Option<&'static str>,
+}
+
+/// Every migration, oldest first. Shipped migrations are never edited; changes get a new one.
+pub static MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "solutions",
+        sql: include_str!("migrations/0001_solutions.sql"),
+        check: None,
+    },
+    Migration {
+        version: 2,
+        name: "examples",
+        sql: include_str!("migrations/0002_examples.sql"),
+        check: None,
+    },
+    Migration {
+        version: 3,
+        name: "params",
+        sql: include_str!("migrations/0003_params.sql"),
+        check: None,
+    },
+    Migration {
+        version: 4,
+        name: "profiles",
+        sql: include_str!("migrations/0004_profiles.sql"),
+        check: None,
+    },
+    Migration {
+        version: 5,
+        name: "inputs_and_answers",
+        sql: include_str!("migrations/0005_inputs_and_answers.sql"),
+        check: Some(include_str!("migrations/0005_inputs_and_answers.check.sql")),
+    },
+    Migration {
+        version: 6,
+        name: "guesses",
+        sql: include_str!("migrations/0006_guesses.sql"),
+        check: None,
+    },
+    Migration {
+        version: 7,
+        name: "puzzles",
+        sql: include_str!("migrations/0007_puzzles.sql"),
+        check: None,
+    },
+];
+
+/// Every migration of the database of runs, see [`DbConfig::resolve_runs`].
+pub static RUNS_MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "runs",
+        sql: include_str!("migrations/runs/0001_runs.sql"),
+        check: None,
+    },
+    Migration {
+        version: 2,
+        name: "bench_results",
+        sql: include_str!("migrations/runs/0002_bench_results.sql"),
+        check: None,
+    },
+    Migration {
+        version: 3,
+        name: "requests",
+        sql: include_str!("migrations/runs/0003_requests.sql"),
+        check: None,
+    },
+];
+
+/// The latest migration applied to the database, 0 for an empty one.
+pub async fn schema_version(conn: &Connection) -> libsql::Result<u32> {
+    if !has_table(conn, "schema_version").await? {
+        return legacy_version(conn).await;
+    }
+    let mut rows = conn
+        .query("SELECT COALESCE(MAX(version), 0) FROM schema_version", ())
+        .await?;
+    match rows.next().await? {
+        Some(row) => row.get(0),
+        None => Ok(0),
+    }
+}
+
+/// Brings the database up to date, returning the migrations it applied.
+pub async fn migrate(conn: &Connection) -> libsql::Result<Vec<&'static Migration>> {
+    apply(conn, MIGRATIONS).await
+}
+
+/// Brings the database of runs up to date, returning the migrations it applied.
+pub async fn migrate_runs(conn: &Connection) -> libsql::Result<Vec<&'static Migration>> {
+    apply(conn, RUNS_MIGRATIONS).await
+}
+
+async fn apply(
+    conn: &Connection,
+    migrations: &'static [Migration],
+) -> libsql::Result<Vec<&'static Migration>> {
+    let current = schema_version(conn).await?;
+    // A database from before `schema_version` gets the table along with the first migration it
+    // needs, so that one the checks turn away is left as it was
+    let mut versioned = has_table(conn, "schema_version").await?;
+
+    let mut applied = Vec::new();
+    for migration in migrations.iter().filter(|m| m.version > current) {
+        if let Some(check) = migration.check {
+            let mut rows = conn.query(check, ()).await?;
+            let mut conflicts = Vec::new();
+            while let Some(row) = rows.next().await? {
+                conflicts.push(row.get::<String>(0)?);
+            }
+            if !conflicts.is_empty() {
+                return Err(libsql::Error::Misuse(format!(
+                    "cannot apply migration {} ({}): {}",
+                    migration.version,
+                    migration.name,
+                    conflicts.join("; ")
+                )));
+            }
+        }
+
+        let tx = conn.transaction().await?;
+        let result = async {
+            if !versioned {
+                create_schema_version(&tx, migrations, current).await?;
+            }
+            tx.execute_batch(migration.sql).await?;
+            tx.execute(
+                "INSERT INTO schema_version (version, name) VALUES (?, ?)",
+                (migration.version, migration.name),
+            )
+            .await
+        }
+        .await;
+        match result {
+            Ok(_) => tx.commit().await?,
+            Err(e) => {
+                tx.rollback().await?;
+                return Err(e);
+            }
+        }
+        versioned = true;
+        applied.push(migration);
+    }
+    if !versioned {
+        create_schema_version(conn, migrations, current).await?;
+    }
+    Ok(applied)
+}
+
+/// Creates `schema_version` in a database from before it, with the migrations up to `current`
+/// recorded as applied.
+async fn create_schema_version(
+    conn: &Connection,
+    migrations: &[Migration],
+    current: u32,
+) -> libsql::Result<()> {
+    conn.execute(
+        "CREATE TABLE schema_version (
+            version INTEGER PRIMARY KEY,
+            name TEXT NOT NULL,
+            applied_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )",
+        (),
+    )
+    .await?;
+    for migration in migrations.iter().take_while(|m| m.version <= current) {
+        conn.execute(
+            "INSERT INTO schema_version (version, name) VALUES (?, ?)",
+            (migration.version, migration.name),
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// Works out the version of a database from before `schema_version`, from the tables it has.
+async fn legacy_version(conn: &Connection) -> libsql::Result<u32> {
+    Ok(if has_table(conn, "inputs").await? {
+        5
+    } else if has_column(conn, "solutions", "profile").await? {
+        4
+    } else if has_column(conn, "solutions", "params").await? {
+        3
+    } else if has_table(conn, "examples").await? {
+        2
+    } else if has_table(conn, "solutions").await? {
+        1
+    } else {
+        0
+    })
+}
+
+async fn has_table(conn: &Connection, table: &str) -> libsql::Result<bool> {
+    let mut rows = conn
+        .query(
+            "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?",
+            [table],
+        )
+        .await?;
+    Ok(rows.next().await?.is_some())
+}
+
+async fn has_column(conn: &Connection, table: &str, column: &str) -> libsql::Result<bool> {
+    let mut rows = conn
+        .query(&format!("PRAGMA table_info({table})"), ())
+        .await?;
+    while let Some(row) = rows.next().await? {
+        if row.get::<String>(1)? == column {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
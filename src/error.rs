@@ -0,0 +1,109 @@
+use std::fmt;
+use std::num::{ParseFloatError, ParseIntError};
+
+/// Error returned by a solution that could not process its input.
+///
+/// Solutions build it with [`SolveError::new`] or [`SolveError::at`] and the
+/// `#[solution]` macro fills in the year, day and part before handing it out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// 1-based line of the input where the error occurred, if known
+    pub line: Option<usize>,
+    /// 1-based column of the input where the error occurred, if known
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        SolveError {
+            year: 0,
+            day: 0,
+            part: 0,
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error located at `fragment`, which must be a slice of `input`.
+    pub fn at(input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        SolveError::new(message).locate(input, fragment)
+    }
+
+    /// Sets the line and column from the position of `fragment` within `input`.
+    ///
+    /// Leaves the location untouched if `fragment` does not point into `input`.
+    pub fn locate(mut self, input: &str, fragment: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (fragment.as_ptr() as usize).wrapping_sub(start);
+
+        if offset <= input.len() {
+            let before = &input[..offset];
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            self.line = Some(before.matches('\n').count() + 1);
+            self.column = Some(offset - line_start + 1);
+        }
+
+        self
+    }
+
+    pub fn with_puzzle(mut self, year: u16, day: u8, part: u8) -> Self {
+        self.year = year;
+        self.day = day;
+        self.part = part;
+        self
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "year {} day {} part {}", self.year, self.day, self.part)?;
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, " at line {line}, column {column}")?,
+            (Some(line), None) => write!(f, " at line {line}")?,
+            _ => {}
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<String> for SolveError {
+    fn from(message: String) -> Self {
+        SolveError::new(message)
+    }
+}
+
+impl From<&str> for SolveError {
+    fn from(message: &str) -> Self {
+        SolveError::new(message)
+    }
+}
+
+impl From<ParseIntError> for SolveError {
+    fn from(e: ParseIntError) -> Self {
+        SolveError::new(e.to_string())
+    }
+}
+
+impl From<ParseFloatError> for SolveError {
+    fn from(e: ParseFloatError) -> Self {
+        SolveError::new(e.to_string())
+    }
+}
+
+/// Parses `token`, a slice of `input`, reporting its position in `input` on failure.
+pub fn parse<T>(input: &str, token: &str) -> Result<T, SolveError>
+where
+    T: std::str::FromStr,
+    T::Err: fmt::Display,
+{
+    token
+        .parse()
+        .map_err(|e: T::Err| SolveError::at(input, token, format!("invalid value '{token}': {e}")))
+}
@@ -0,0 +1,210 @@
+use std::fmt;
+use std::io::{self, Read};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::config::ConfigFile;
+
+/// Environment variable with the wall-clock limit of a solution, in seconds, 0 for none
+pub const TIMEOUT_ENV: &str = "AOC_TIMEOUT";
+
+/// Environment variable with the memory limit of a solution, in MiB, 0 for none
+pub const MEMORY_LIMIT_ENV: &str = "AOC_MEMORY_LIMIT";
+
+/// Set in the child process, which runs the solution itself rather than another child
+pub const ISOLATED_ENV: &str = "AOC_ISOLATED";
+
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// In MiB
+pub const DEFAULT_MEMORY_LIMIT: u64 = 4096;
+
+/// What a solution running in a child process is allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Wall-clock time, after which the child is killed
+    pub timeout: Option<Duration>,
+    /// Heap of the child, in bytes; only enforced on Unix
+    pub memory: Option<u64>,
+}
+
+impl Limits {
+    /// Reads the limits from, in order: `AOC_TIMEOUT` and `AOC_MEMORY_LIMIT`, `timeout` and
+    /// `memory_limit` in `aoc.conf`, and the defaults.
+    pub fn resolve() -> Result<Self, String> {
+        let file = ConfigFile::read()?;
+        let timeout = setting(TIMEOUT_ENV, file.timeout)?.unwrap_or(DEFAULT_TIMEOUT.as_secs());
+        let memory = setting(MEMORY_LIMIT_ENV, file.memory_limit)?.unwrap_or(DEFAULT_MEMORY_LIMIT);
+        Ok(Limits {
+            timeout: (timeout > 0).then_some(Duration::from_secs(timeout)),
+            memory: (memory > 0).then_some(memory << 20),
+        })
+    }
+}
+
+/// A number from the environment variable `name`, or else from the config file.
+fn setting(name: &str, file: Option<String>) -> Result<Option<u64>, String> {
+    match std::env::var(name).ok().or(file) {
+        Some(value) => value
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|e| format!("{name}: invalid value {value:?}: {e}")),
+        None => Ok(None),
+    }
+}
+
+/// How a solution running in a child process ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    /// With the panic message
+    Panicked(String),
+    TimedOut(Duration),
+    /// With the limit, in bytes
+    OutOfMemory(u64),
+    /// Died any other way, e.g. killed by a signal
+    Crashed(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Passed => write!(f, "passed"),
+            Self::Panicked(message) => write!(f, "{message}"),
+            Self::TimedOut(timeout) => write!(f, "timed out after {timeout:?}, see {TIMEOUT_ENV}"),
+            Self::OutOfMemory(limit) => {
+                write!(
+                    f,
+                    "ran out of memory, over {} MiB, see {MEMORY_LIMIT_ENV}",
+                    limit >> 20
+                )
+            }
+            Self::Crashed(reason) => write!(f, "crashed: {reason}"),
+        }
+    }
+}
+
+/// Runs `command` within the limits, and tells how it ended from its exit status and standard
+/// error.
+pub fn run_isolated(command: Command, limits: &Limits) -> io::Result<Outcome> {
+    run_isolated_with_output(command, limits).map(|(outcome, _)| outcome)
+}
+
+/// Like [`run_isolated`], also returning what the child wrote to its standard output.
+pub fn run_isolated_with_output(
+    mut command: Command,
+    limits: &Limits,
+) -> io::Result<(Outcome, String)> {
+    #[cfg(unix)]
+    if let Some(bytes) = limits.memory {
+        use std::os::unix::process::CommandExt;
+        // Unlike the address space, the data limit leaves alone what threads and allocators merely
+        // reserve
+        let limit = libc::rlimit {
+            rlim_cur: bytes as libc::rlim_t,
+            rlim_max: bytes as libc::rlim_t,
+        };
+        // SAFETY: setrlimit is async-signal-safe, and nothing else runs between fork and exec
+        unsafe {
+            command.pre_exec(move || match libc::setrlimit(libc::RLIMIT_DATA, &limit) {
+                0 => Ok(()),
+                _ => Err(io::Error::last_os_error()),
+            });
+        }
+    }
+
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    // Read as they come, or a chatty child would block on a full pipe
+    let stdout = read_on_thread(child.stdout.take().unwrap());
+    let stderr = read_on_thread(child.stderr.take().unwrap());
+
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+        if limits
+            .timeout
+            .is_some_and(|timeout| start.elapsed() >= timeout)
+        {
+            child.kill()?;
+            child.wait()?;
+            break None;
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    let Some(status) = status else {
+        let timeout = limits.timeout.unwrap_or_default();
+        return Ok((Outcome::TimedOut(timeout), stdout));
+    };
+    if status.success() {
+        return Ok((Outcome::Passed, stdout));
+    }
+    // Rust aborts on a failed allocation, and C++ dependencies such as z3 terminate on `bad_alloc`
+    let failed_allocation = ["memory allocation of", "std::bad_alloc"]
+        .iter()
+        .any(|m| stderr.contains(m));
+    if let Some(limit) = limits.memory.filter(|_| failed_allocation) {
+        return Ok((Outcome::OutOfMemory(limit), stdout));
+    }
+    let outcome = match panic_message(&stderr) {
+        Some(message) => Outcome::Panicked(message),
+        None => Outcome::Crashed(format!("{status}\n{}", stderr.trim())),
+    };
+    Ok((outcome, stdout))
+}
+
+fn read_on_thread(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut content = String::new();
+        let _ = pipe.read_to_string(&mut content);
+        content
+    })
+}
+
+/// The message and location of the first panic in the standard error of a test, without the notes
+/// after it.
+fn panic_message(stderr: &str) -> Option<String> {
+    let (_, rest) = stderr.split_once(" panicked at ")?;
+    let (location, message) = rest.split_once('\n')?;
+    let message = message.split("\nnote: ").next().unwrap_or(message);
+    let message = message
+        .split("\nstack backtrace:")
+        .next()
+        .unwrap_or(message);
+    Some(format!(
+        "{}\n  at {}",
+        message.trim(),
+        location.trim_end_matches(':')
+    ))
+}
+
+/// Runs the test `name` of the current test binary in a child process of its own, within the
+/// configured limits, panicking with how it failed. In that child, runs `test` itself.
+#[track_caller]
+pub fn isolated(name: &str, test: impl FnOnce()) {
+    if std::env::var_os(ISOLATED_ENV).is_some() {
+        return test();
+    }
+    let limits = Limits::resolve().unwrap_or_else(|e| panic!("{e}"));
+    let binary =
+        std::env::current_exe().unwrap_or_else(|e| panic!("cannot find the test binary: {e}"));
+    let mut command = Command::new(binary);
+    // Whatever ignored it in this process was already overridden to get here
+    command
+        .args([name, "--exact", "--nocapture", "--include-ignored"])
+        .env(ISOLATED_ENV, "1");
+    match run_isolated(command, &limits) {
+        Ok(Outcome::Passed) => {}
+        Ok(outcome) => panic!("{outcome}"),
+        Err(e) => panic!("cannot run {name} in a child process: {e}"),
+    }
+}
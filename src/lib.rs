@@ -1,12 +1,101 @@
-pub use aoc_macros::solution;
+use std::any::Any;
+
+pub use aoc_macros::{day, solution};
+pub use crypto::{INPUT_KEY_ENV, INPUT_KEY_FILE_ENV, InputKey, decrypt_input, is_encrypted};
+pub use db::{
+    DB_ENV, DbConfig, MIGRATIONS, Migration, RUNS_DB_ENV, RUNS_MIGRATIONS, migrate, migrate_runs,
+    schema_version,
+};
+pub use error::{SolveError, parse};
+pub use isolate::{
+    ISOLATED_ENV, Limits, MEMORY_LIMIT_ENV, Outcome, TIMEOUT_ENV, isolated, run_isolated,
+    run_isolated_with_output,
+};
+pub use params::Params;
+pub use rng::Rng;
+pub use site::{BASE_URL_ENV, MIN_REQUEST_INTERVAL, SESSION_ENV, Site};
+
+/// Output of a [`Staged::try_parse`], consumed by [`Staged::try_solve_parsed`].
+pub type Parsed = Box<dyn Any>;
 
 pub trait Solution: Send + Sync {
     fn year(&self) -> u16;
     fn day(&self) -> u8;
     fn part(&self) -> u8;
-    fn solve(&self, input: &str) -> String;
+
+    /// Label telling apart competing implementations of the same part, if any.
+    fn variant(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Whether this variant is the one others are cross-checked against.
+    ///
+    /// When no variant of a puzzle is marked, the unlabelled one is used.
+    fn is_reference(&self) -> bool {
+        false
+    }
+
+    /// A random input for cross-checking variants, from the module's
+    /// optional `fn generate(&mut Rng) -> String`.
+    fn generate(&self, _seed: u64) -> Option<String> {
+        None
+    }
+
+    /// Declared parameters and their defaults, used for the real input. Empty for the part of a
+    /// `#[day]` whose function does not take them.
+    fn params(&self) -> &'static [(&'static str, i64)] {
+        &[]
+    }
+
+    /// Solves `input` with the declared parameters overridden by `params`.
+    fn try_solve_with(&self, input: &str, params: &Params) -> Result<String, SolveError>;
+
+    fn try_solve(&self, input: &str) -> Result<String, SolveError> {
+        self.try_solve_with(input, &Params::default())
+    }
+
+    /// Like [`Solution::try_solve`], panicking with the error message on failure.
+    fn solve(&self, input: &str) -> String {
+        self.try_solve(input).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// The separate parse and solve stages of a solution declared with `#[day]`.
+    fn staged(&self) -> Option<&dyn Staged> {
+        None
+    }
+}
+
+/// A solution whose parsing is shared between the parts of a day, so that
+/// parsing and solving can be run and timed on their own.
+pub trait Staged {
+    /// Path of the `#[day]` module; its parts accept each other's parsed input.
+    fn module(&self) -> &'static str;
+    fn try_parse(&self, input: &str) -> Result<Parsed, SolveError>;
+    fn try_solve_parsed(&self, parsed: &Parsed, params: &Params) -> Result<String, SolveError>;
 }
 
 inventory::collect!(&'static dyn Solution);
 
-mod solutions;
+/// A solution whose module a `cfg` leaves out of this build, e.g. a disabled cargo feature.
+pub struct CompiledOut {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub variant: Option<&'static str>,
+    /// The predicate that failed, e.g. `feature = "z3"`
+    pub cfg: &'static str,
+}
+
+/// Every solution compiled out of this build, as found by `build.rs`.
+pub static COMPILED_OUT: &[CompiledOut] = include!(concat!(env!("OUT_DIR"), "/compiled_out.rs"));
+
+mod config;
+mod crypto;
+mod db;
+mod error;
+mod isolate;
+mod params;
+mod rng;
+mod site;
+#[cfg(feature = "y2025")]
+mod y2025;
@@ -1,12 +1,32 @@
-use clap::{Parser, Subcommand};
-use libsql::{Builder, Connection};
+use aoc_runner::{
+    COMPILED_OUT, CompiledOut, DB_ENV, DbConfig, MIGRATIONS, Params, Solution, migrate,
+    migrate_runs, schema_version,
+};
+use aoc_runner::{INPUT_KEY_ENV, INPUT_KEY_FILE_ENV, InputKey, decrypt_input, is_encrypted};
+use aoc_runner::{Limits, Outcome, run_isolated_with_output};
+use aoc_runner::{MIN_REQUEST_INTERVAL, Site};
+use clap::{Parser, Subcommand, ValueEnum};
+use libsql::Connection;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
 use std::fs;
-use std::io::{self, Read};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+use std::time::{Duration, Instant, SystemTime};
 
 #[derive(Parser)]
 #[command(name = "cli")]
 #[command(about = "Advent of Code CLI")]
 struct Cli {
+    /// Database to use: a file path, `:memory:` or a libsql URL [default: $AOC_DB, then aoc.conf,
+    /// then aoc.db]
+    #[arg(long, global = true)]
+    db: Option<String>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -15,44 +35,220 @@ struct Cli {
 enum Commands {
     /// Database operations (init, reset, list, upsert, delete, read)
     Db {
+        /// Profile (AoC account) whose inputs and answers to use; examples are shared by all
+        /// profiles
+        #[arg(long, global = true, default_value = "default")]
+        profile: String,
         #[command(subcommand)]
         command: DbCommands,
     },
+    /// Run solutions and check their answers against the stored outputs
+    Run {
+        /// Year or range of years (e.g. 2025 or 2015-2025)
+        year: Option<Span>,
+        /// Day or range of days (e.g. 8 or 1-12)
+        day: Option<Span>,
+        /// Part or range of parts (e.g. 1 or 1-2)
+        part: Option<Span>,
+        /// Only run the given variant ("default" for solutions without one)
+        #[arg(long)]
+        variant: Option<String>,
+        /// Override puzzle parameters (e.g. connections=10,rows=3)
+        #[arg(long)]
+        params: Option<Params>,
+        /// Read the input from a file instead of the database
+        #[arg(short, long, conflicts_with = "stdin")]
+        file: Option<String>,
+        /// Read the input from stdin instead of the database
+        #[arg(long, conflicts_with = "file")]
+        stdin: bool,
+        /// Check the stored examples first, and stop at the first one that fails
+        #[arg(long)]
+        examples: bool,
+        /// Profile (AoC account) whose inputs and answers to use
+        #[arg(long, default_value = "default")]
+        profile: String,
+        /// Do not record the runs in the database of runs
+        #[arg(long)]
+        no_record: bool,
+    },
+    /// Run one solution on the input from stdin, for `run` to call in a child process of its own
+    #[command(hide = true)]
+    Solve {
+        year: u16,
+        day: u8,
+        part: u8,
+        #[arg(long)]
+        variant: Option<String>,
+        #[arg(long, default_value = "")]
+        params: Params,
+    },
+    /// Rebuild and run a puzzle, examples first, every time a source file changes
+    Watch {
+        year: u16,
+        day: u8,
+        part: Option<u8>,
+        /// Profile (AoC account) whose input and answers to use
+        #[arg(long, default_value = "default")]
+        profile: String,
+    },
+    /// Download the input of a puzzle from the site and store it
+    Fetch {
+        year: u16,
+        day: u8,
+        /// Profile (AoC account) to fetch the input of, with its session cookie
+        #[arg(long, default_value = "default")]
+        profile: String,
+        /// Download the input again even if one is stored
+        #[arg(long)]
+        force: bool,
+    },
+    /// Submit an answer to the site and record the verdict
+    Submit {
+        year: u16,
+        day: u8,
+        part: u8,
+        /// Answer to submit [default: the reference solution's answer on the stored input]
+        answer: Option<String>,
+        /// Profile (AoC account) to submit as, with its session cookie
+        #[arg(long, default_value = "default")]
+        profile: String,
+        /// Submit even an answer the earlier guesses rule out
+        #[arg(long)]
+        force: bool,
+    },
+    /// Create the solution module of a new puzzle from a template, and its empty input and example
+    /// rows
+    New {
+        year: u16,
+        day: u8,
+        /// Template with `{{year}}` and `{{day}}` placeholders [default: templates/day.rs]
+        #[arg(long)]
+        template: Option<String>,
+        /// Profile (AoC account) to create the empty input row of
+        #[arg(long, default_value = "default")]
+        profile: String,
+    },
+    /// Record benchmark results in the database, or compare them between commits
+    Bench {
+        #[command(subcommand)]
+        command: BenchCommands,
+    },
+}
+
+#[derive(Subcommand)]
+enum BenchCommands {
+    /// Run the benchmarks and store their results under the current commit
+    Record {
+        /// Only run the benchmarks matching these filters, as with `cargo bench -- <filter>`
+        filters: Vec<String>,
+    },
+    /// Compare the latest results of a baseline commit or tag with those of the current commit
+    Compare {
+        /// Commit, tag or branch the results are compared against
+        baseline: String,
+        /// Commit, tag or branch to compare instead of the current checkout
+        #[arg(long)]
+        against: Option<String>,
+        /// Slowdown of a median, in percent, beyond which a solution counts as regressed
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+}
+
+/// An inclusive range of values given as `N` or `A-B` on the command line
+#[derive(Clone, Copy)]
+struct Span {
+    start: u16,
+    end: u16,
+}
+
+impl Span {
+    fn contains(&self, value: u16) -> bool {
+        self.start <= value && value <= self.end
+    }
+}
+
+impl FromStr for Span {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |v: &str| {
+            v.trim()
+                .parse::<u16>()
+                .map_err(|e| format!("invalid value '{v}': {e}"))
+        };
+        let (start, end) = match s.split_once('-') {
+            Some((start, end)) => (parse(start)?, parse(end)?),
+            None => (parse(s)?, parse(s)?),
+        };
+        if start > end {
+            return Err(format!("empty range '{s}'"));
+        }
+        Ok(Span { start, end })
+    }
 }
 
 #[derive(Subcommand)]
 enum DbCommands {
-    /// Initialize the database (create tables)
+    /// Initialize the database (apply all migrations, as every other command does on open)
     Init,
     /// Reset the database (delete all data)
     Reset,
-    /// List all solutions
-    List,
+    /// Apply the pending schema migrations
+    Migrate,
+    /// Show the schema version and which migrations are pending
+    Status,
+    /// List all solutions of the profile with their puzzle's title and tags, or the examples with
+    /// `list examples`
+    List {
+        #[command(subcommand)]
+        command: Option<ListCommands>,
+        /// Only list the puzzles with this tag
+        #[arg(long)]
+        tag: Option<String>,
+    },
     /// Insert or update data
     Upsert {
         #[command(subcommand)]
         command: UpsertCommands,
     },
-    /// Delete a solution
+    /// Delete the answer of a part, or a day's input and all its answers
     Delete {
         year: u16,
         day: u8,
-        part: u8,
+        part: Option<u8>,
     },
     /// Read data from the database
     Read {
         #[command(subcommand)]
         command: ReadCommands,
     },
+    /// Encrypt the plain inputs of every profile with the input key
+    Encrypt,
+    /// Decrypt the inputs of every profile, e.g. before changing the key
+    Decrypt,
+    /// Write the profile's inputs, answers and guesses, and the examples, as files under `dir`
+    Export { dir: String },
+    /// Upsert everything found in a directory written by `db export`
+    Import { dir: String },
+    /// Show the recorded runs of a solution, oldest first
+    History {
+        year: u16,
+        day: u8,
+        part: u8,
+        /// Only show the given variant ("default" for solutions without one)
+        #[arg(long)]
+        variant: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
 enum UpsertCommands {
-    /// Upsert the input for a given year, day, part (reads from stdin by default)
+    /// Upsert the input for a given year, day, shared by both parts (reads from stdin by default)
     Input {
         year: u16,
         day: u8,
-        part: u8,
         /// Value as a string
         #[arg(long, conflicts_with = "file")]
         value: Option<String>,
@@ -72,21 +268,134 @@ enum UpsertCommands {
         #[arg(short, long, conflicts_with = "value")]
         file: Option<String>,
     },
+    /// Upsert an example input and its expected output (reads the input from stdin by default)
+    Example {
+        year: u16,
+        day: u8,
+        part: u8,
+        /// Expected output for the example
+        #[arg(long)]
+        output: String,
+        /// Label telling apart several examples of the same part
+        #[arg(long, default_value = "")]
+        label: String,
+        /// Puzzle parameters for the example (e.g. connections=10)
+        #[arg(long, default_value = "")]
+        params: Params,
+        /// Input as a string
+        #[arg(long, conflicts_with = "file")]
+        value: Option<String>,
+        /// Read input from a file
+        #[arg(short, long, conflicts_with = "value")]
+        file: Option<String>,
+    },
+    /// Upsert the puzzle parameters of the input for a given year, day
+    Params {
+        year: u16,
+        day: u8,
+        /// Parameters as name=value pairs (e.g. connections=1000)
+        params: Params,
+    },
+    /// Upsert the title, release time, notes or tags of a puzzle
+    Puzzle {
+        year: u16,
+        day: u8,
+        #[arg(long)]
+        title: Option<String>,
+        /// Release time in UTC as `YYYY-MM-DD HH:MM:SS` [default: midnight EST on the day]
+        #[arg(long)]
+        released_at: Option<String>,
+        #[arg(long)]
+        notes: Option<String>,
+        /// Tag to add, e.g. union-find (repeatable)
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+        /// Tag to remove (repeatable)
+        #[arg(long = "untag")]
+        untags: Vec<String>,
+    },
+    /// Mark a part as completed by the profile
+    Completed {
+        year: u16,
+        day: u8,
+        part: u8,
+        /// Completion time in UTC as `YYYY-MM-DD HH:MM:SS` [default: now]
+        #[arg(long)]
+        at: Option<String>,
+    },
+    /// Record an answer given on the site and its verdict, so the runner can warn before it is
+    /// given again
+    Guess {
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: String,
+        /// What the site said; a correct guess is also stored as the expected output and completes
+        /// the part
+        verdict: Verdict,
+    },
+}
+
+/// The site's response to a submitted answer
+#[derive(Clone, Copy, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    fn from_db(verdict: &str) -> Option<Self> {
+        Self::value_variants()
+            .iter()
+            .copied()
+            .find(|v| v.as_str() == verdict)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::Wrong => "wrong",
+            Self::TooHigh => "too_high",
+            Self::TooLow => "too_low",
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum ListCommands {
+    /// List the examples, optionally for a given year and day
+    Examples { year: Option<u16>, day: Option<u8> },
+    /// List the profiles and how many days each has an input for
+    Profiles,
+    /// List the answers guessed by the profile, optionally for a given year and day
+    Guesses { year: Option<u16>, day: Option<u8> },
+    /// List the puzzles with their title, release time, tags and notes
+    Puzzles {
+        /// Only list the puzzles with this tag
+        #[arg(long)]
+        tag: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
 enum ReadCommands {
     /// Read the expected output for a given year, day, part
-    Output {
-        year: u16,
-        day: u8,
-        part: u8,
-    },
-    /// Read the input for a given year, day, part
-    Input {
+    Output { year: u16, day: u8, part: u8 },
+    /// Read the input for a given year, day
+    Input { year: u16, day: u8 },
+    /// Read an example input (or its expected output with --output)
+    Example {
         year: u16,
         day: u8,
         part: u8,
+        #[arg(long, default_value = "")]
+        label: String,
+        /// Print the expected output instead of the input
+        #[arg(long)]
+        output: bool,
     },
 }
 
@@ -94,77 +403,433 @@ enum ReadCommands {
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
 
-    let db_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc.db");
-    let db = Builder::new_local(db_path).build().await?;
+    // Given all it needs by `run`, without the database
+    if let Commands::Solve {
+        year,
+        day,
+        part,
+        variant,
+        params,
+    } = &cli.command
+    {
+        return solve_child(*year, *day, *part, variant.as_deref(), params);
+    }
+
+    let config = DbConfig::resolve(cli.db.as_deref())?;
+    let db = config.open().await?;
     let conn = db.connect()?;
+    let key = InputKey::load()?;
+    let key = key.as_ref();
+
+    // `db migrate` and `db status` deal with pending migrations themselves
+    if !matches!(
+        cli.command,
+        Commands::Db {
+            command: DbCommands::Migrate | DbCommands::Status,
+            ..
+        }
+    ) {
+        migrate(&conn).await?;
+    }
+
+    // AoC asks not to publish inputs, so the checked-in database only takes encrypted ones
+    if config.is_checked_in() {
+        let stores_input = matches!(
+            cli.command,
+            Commands::Fetch { .. }
+                | Commands::Db {
+                    command: DbCommands::Upsert {
+                        command: UpsertCommands::Input { .. }
+                    } | DbCommands::Import { .. },
+                    ..
+                }
+        );
+        let decrypts = matches!(
+            cli.command,
+            Commands::Db {
+                command: DbCommands::Decrypt,
+                ..
+            }
+        );
+        if decrypts {
+            return Err(
+                format!("Refusing to decrypt the inputs of the checked-in {config}").into(),
+            );
+        }
+        if stores_input && key.is_none() {
+            return Err(format!(
+                "Refusing to store a plain input in the checked-in {config}, \
+                 set {INPUT_KEY_ENV} or {INPUT_KEY_FILE_ENV} to encrypt it"
+            )
+            .into());
+        }
+    }
 
     match cli.command {
-        Commands::Db { command } => match command {
+        Commands::Db { profile, command } => match command {
             DbCommands::Init => {
-                create_schema(&conn).await?;
                 println!("Database initialized");
             }
+            DbCommands::Migrate => {
+                let applied = migrate(&conn).await?;
+                for migration in &applied {
+                    println!("Applied {:04} {}", migration.version, migration.name);
+                }
+                if applied.is_empty() {
+                    println!("Database is up to date");
+                }
+            }
+            DbCommands::Status => {
+                print_status(&conn).await?;
+            }
             DbCommands::Reset => {
                 reset_db(&conn).await?;
                 println!("Database reset");
             }
-            DbCommands::List => {
-                list_solutions(&conn).await?;
+            DbCommands::List { command: None, tag } => {
+                list_answers(&conn, &profile, tag.as_deref()).await?;
+            }
+            DbCommands::List {
+                command: Some(ListCommands::Examples { year, day }),
+                ..
+            } => {
+                list_examples(&conn, year, day).await?;
+            }
+            DbCommands::List {
+                command: Some(ListCommands::Profiles),
+                ..
+            } => {
+                list_profiles(&conn).await?;
+            }
+            DbCommands::List {
+                command: Some(ListCommands::Guesses { year, day }),
+                ..
+            } => {
+                list_guesses(&conn, &profile, year, day).await?;
+            }
+            DbCommands::List {
+                command: Some(ListCommands::Puzzles { tag }),
+                ..
+            } => {
+                list_puzzles(&conn, tag.as_deref()).await?;
             }
             DbCommands::Upsert { command } => match command {
-                UpsertCommands::Input { year, day, part, value, file } => {
+                UpsertCommands::Input {
+                    year,
+                    day,
+                    value,
+                    file,
+                } => {
+                    let data = read_upsert_value(value, file)?;
+                    upsert_input(&conn, &profile, year, day, &data, key).await?;
+                    println!("Upserted input: profile={profile}, year={year}, day={day}");
+                }
+                UpsertCommands::Output {
+                    year,
+                    day,
+                    part,
+                    value,
+                    file,
+                } => {
                     let data = read_upsert_value(value, file)?;
-                    upsert_input(&conn, year, day, part, &data).await?;
-                    println!("Upserted input: year={year}, day={day}, part={part}");
+                    upsert_output(&conn, &profile, year, day, part, &data).await?;
+                    println!(
+                        "Upserted output: profile={profile}, year={year}, day={day}, part={part}"
+                    );
                 }
-                UpsertCommands::Output { year, day, part, value, file } => {
+                UpsertCommands::Example {
+                    year,
+                    day,
+                    part,
+                    output,
+                    label,
+                    params,
+                    value,
+                    file,
+                } => {
                     let data = read_upsert_value(value, file)?;
-                    upsert_output(&conn, year, day, part, &data).await?;
-                    println!("Upserted output: year={year}, day={day}, part={part}");
+                    upsert_example(&conn, year, day, part, &label, &data, &output, &params).await?;
+                    println!(
+                        "Upserted example: year={year}, day={day}, part={part}, label={label:?}"
+                    );
+                }
+                UpsertCommands::Params { year, day, params } => {
+                    upsert_params(&conn, &profile, year, day, &params).await?;
+                    println!(
+                        "Upserted params: profile={profile}, year={year}, day={day}, \
+                         params={params}"
+                    );
+                }
+                UpsertCommands::Puzzle {
+                    year,
+                    day,
+                    title,
+                    released_at,
+                    notes,
+                    tags,
+                    untags,
+                } => {
+                    upsert_puzzle(
+                        &conn,
+                        year,
+                        day,
+                        title.as_deref(),
+                        released_at.as_deref(),
+                        notes.as_deref(),
+                    )
+                    .await?;
+                    tag_puzzle(&conn, year, day, &tags, &untags).await?;
+                    println!("Upserted puzzle: year={year}, day={day}");
+                }
+                UpsertCommands::Completed {
+                    year,
+                    day,
+                    part,
+                    at,
+                } => {
+                    mark_completed(&conn, &profile, year, day, part, at.as_deref()).await?;
+                    println!(
+                        "Marked completed: profile={profile}, year={year}, day={day}, part={part}"
+                    );
+                }
+                UpsertCommands::Guess {
+                    year,
+                    day,
+                    part,
+                    answer,
+                    verdict,
+                } => {
+                    let answer = answer.trim();
+                    upsert_guess(&conn, &profile, year, day, part, answer, verdict, None).await?;
+                    if let Verdict::Correct = verdict {
+                        upsert_output(&conn, &profile, year, day, part, answer).await?;
+                        mark_completed(&conn, &profile, year, day, part, None).await?;
+                    }
+                    println!(
+                        "Upserted guess: profile={profile}, year={year}, day={day}, part={part}, \
+                         verdict={}",
+                        verdict.as_str()
+                    );
                 }
             },
-            DbCommands::Delete { year, day, part } => {
-                delete_solution(&conn, year, day, part).await?;
-                println!("Deleted: year={year}, day={day}, part={part}");
+            DbCommands::Delete {
+                year,
+                day,
+                part: Some(part),
+            } => {
+                delete_answer(&conn, &profile, year, day, part).await?;
+                println!("Deleted answer: profile={profile}, year={year}, day={day}, part={part}");
+            }
+            DbCommands::Delete {
+                year,
+                day,
+                part: None,
+            } => {
+                delete_input(&conn, &profile, year, day).await?;
+                println!("Deleted input and answers: profile={profile}, year={year}, day={day}");
+            }
+            DbCommands::Encrypt => {
+                let key = key.ok_or(format!(
+                    "No key to encrypt with, set {INPUT_KEY_ENV} or {INPUT_KEY_FILE_ENV}"
+                ))?;
+                let count = crypt_inputs(&conn, key, true).await?;
+                // Drop the free pages, which still hold the plain inputs
+                conn.execute("VACUUM", ()).await?;
+                println!("Encrypted {count} inputs");
+            }
+            DbCommands::Decrypt => {
+                let key = key.ok_or(format!(
+                    "No key to decrypt with, set {INPUT_KEY_ENV} or {INPUT_KEY_FILE_ENV}"
+                ))?;
+                let count = crypt_inputs(&conn, key, false).await?;
+                println!("Decrypted {count} inputs");
+            }
+            DbCommands::Export { dir } => {
+                let days = export_db(&conn, &profile, Path::new(&dir), key).await?;
+                println!("Exported {days} days: profile={profile}, dir={dir}");
+            }
+            DbCommands::Import { dir } => {
+                let tx = conn.transaction().await?;
+                match import_db(&tx, &profile, Path::new(&dir), key).await {
+                    Ok(days) => {
+                        tx.commit().await?;
+                        println!("Imported {days} days: profile={profile}, dir={dir}");
+                    }
+                    Err(e) => {
+                        tx.rollback().await?;
+                        return Err(e);
+                    }
+                }
+            }
+            DbCommands::History {
+                year,
+                day,
+                part,
+                variant,
+            } => {
+                let runs = connect_runs().await?;
+                print_history(&runs, &profile, year, day, part, variant.as_deref()).await?;
             }
             DbCommands::Read { command } => match command {
                 ReadCommands::Output { year, day, part } => {
-                    let solution = read_solution(&conn, year, day, part).await?;
-                    print!("{solution}");
+                    let answer = read_answer(&conn, &profile, year, day, part).await?;
+                    print!("{answer}");
                 }
-                ReadCommands::Input { year, day, part } => {
-                    let input = read_input(&conn, year, day, part).await?;
+                ReadCommands::Input { year, day } => {
+                    let stored = read_input(&conn, &profile, year, day).await?;
+                    let input = decrypt(&stored, key, &profile, year, day)?
+                        .ok_or_else(|| no_key(&profile, year, day))?;
                     print!("{input}");
                 }
+                ReadCommands::Example {
+                    year,
+                    day,
+                    part,
+                    label,
+                    output,
+                } => {
+                    let (input, expected) = read_example(&conn, year, day, part, &label).await?;
+                    print!("{}", if output { expected } else { input });
+                }
             },
         },
+        Commands::Run {
+            year,
+            day,
+            part,
+            variant,
+            params,
+            file,
+            stdin,
+            examples,
+            profile,
+            no_record,
+        } => {
+            let input = match (file, stdin) {
+                (Some(path), _) => Some(fs::read_to_string(&path)?),
+                (None, true) => Some(read_upsert_value(None, None)?),
+                (None, false) => None,
+            };
+            let variant = variant.as_deref();
+            let runs = match no_record {
+                true => None,
+                false => Some(connect_runs().await?),
+            };
+            run_solutions(
+                &conn,
+                runs.as_ref(),
+                &profile,
+                key,
+                year,
+                day,
+                part,
+                variant,
+                params,
+                input,
+                examples,
+            )
+            .await?;
+        }
+        Commands::Solve { .. } => unreachable!("handled before the database is opened"),
+        Commands::Watch {
+            year,
+            day,
+            part,
+            profile,
+        } => {
+            watch_day(&config, &profile, year, day, part)?;
+        }
+        Commands::Fetch {
+            year,
+            day,
+            profile,
+            force,
+        } => {
+            let runs = connect_runs().await?;
+            fetch_input(&conn, &runs, &profile, key, year, day, force).await?;
+        }
+        Commands::Submit {
+            year,
+            day,
+            part,
+            answer,
+            profile,
+            force,
+        } => {
+            let answer = match answer {
+                Some(answer) => answer.trim().to_string(),
+                None => solve_stored(&conn, &profile, key, year, day, part).await?,
+            };
+            let runs = connect_runs().await?;
+            submit_answer(&conn, &runs, &profile, year, day, part, &answer, force).await?;
+        }
+        Commands::New {
+            year,
+            day,
+            template,
+            profile,
+        } => {
+            scaffold_day(&conn, &profile, year, day, template.as_deref()).await?;
+        }
+        Commands::Bench {
+            command: BenchCommands::Record { filters },
+        } => {
+            let runs = connect_runs().await?;
+            record_benches(&runs, &config, &filters).await?;
+        }
+        Commands::Bench {
+            command:
+                BenchCommands::Compare {
+                    baseline,
+                    against,
+                    threshold,
+                },
+        } => {
+            let runs = connect_runs().await?;
+            compare_benches(&runs, &baseline, against.as_deref(), threshold).await?;
+        }
     }
 
     Ok(())
 }
 
-async fn create_schema(conn: &Connection) -> Result<(), libsql::Error> {
-    conn.execute(
-        "CREATE TABLE IF NOT EXISTS solutions (
-            year INTEGER NOT NULL,
-            day INTEGER NOT NULL,
-            part INTEGER NOT NULL,
-            input TEXT NOT NULL,
-            output TEXT NOT NULL,
-            PRIMARY KEY (year, day, part)
-        )",
-        (),
-    )
-    .await?;
+/// Drops every table, then recreates the schema from scratch.
+async fn reset_db(conn: &Connection) -> Result<(), libsql::Error> {
+    let mut rows = conn
+        .query(
+            "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'",
+            (),
+        )
+        .await?;
+    let mut tables = Vec::new();
+    while let Some(row) = rows.next().await? {
+        tables.push(row.get::<String>(0)?);
+    }
+    for table in tables {
+        conn.execute(&format!("DROP TABLE \"{table}\""), ()).await?;
+    }
+    migrate(conn).await?;
     Ok(())
 }
 
-async fn reset_db(conn: &Connection) -> Result<(), libsql::Error> {
-    conn.execute("DROP TABLE IF EXISTS solutions", ()).await?;
+async fn print_status(conn: &Connection) -> Result<(), libsql::Error> {
+    let version = schema_version(conn).await?;
+    println!("Schema version {version}");
+    for migration in MIGRATIONS {
+        let status = if migration.version <= version {
+            "applied"
+        } else {
+            "pending"
+        };
+        println!("{:04}\t{}\t{status}", migration.version, migration.name);
+    }
     Ok(())
 }
 
-fn read_upsert_value(value: Option<String>, file: Option<String>) -> Result<String, Box<dyn std::error::Error>> {
+fn read_upsert_value(
+    value: Option<String>,
+    file: Option<String>,
+) -> Result<String, Box<dyn std::error::Error>> {
     match (value, file) {
         (Some(v), None) => Ok(v),
         (None, Some(path)) => Ok(fs::read_to_string(&path)?),
@@ -177,98 +842,2234 @@ fn read_upsert_value(value: Option<String>, file: Option<String>) -> Result<Stri
     }
 }
 
+/// Stores an input, encrypted when there is a key.
 async fn upsert_input(
     conn: &Connection,
+    profile: &str,
     year: u16,
     day: u8,
-    part: u8,
     input: &str,
+    key: Option<&InputKey>,
 ) -> Result<(), libsql::Error> {
+    let stored = match key {
+        Some(key) if !input.is_empty() => key.encrypt(input),
+        _ => input.to_string(),
+    };
     conn.execute(
-        "INSERT INTO solutions (year, day, part, input, output) VALUES (?, ?, ?, ?, '')
-         ON CONFLICT(year, day, part) DO UPDATE SET input = excluded.input",
-        (year, day, part, input),
+        "INSERT INTO inputs (profile, year, day, input) VALUES (?, ?, ?, ?)
+         ON CONFLICT(profile, year, day) DO UPDATE SET input = excluded.input",
+        (profile, year, day, stored),
     )
     .await?;
     Ok(())
 }
 
+/// Encrypts the plain inputs, or decrypts the encrypted ones, returning how many changed.
+async fn crypt_inputs(
+    conn: &Connection,
+    key: &InputKey,
+    encrypt: bool,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    let tx = conn.transaction().await?;
+    let mut rows = tx
+        .query(
+            "SELECT profile, year, day, input FROM inputs WHERE input != ''",
+            (),
+        )
+        .await?;
+    let mut changed = Vec::new();
+    while let Some(row) = rows.next().await? {
+        let stored: String = row.get(3)?;
+        if is_encrypted(&stored) != encrypt {
+            let input = if encrypt {
+                key.encrypt(&stored)
+            } else {
+                key.decrypt(&stored)?
+            };
+            changed.push((
+                row.get::<String>(0)?,
+                row.get::<u32>(1)?,
+                row.get::<u32>(2)?,
+                input,
+            ));
+        }
+    }
+    for (profile, year, day, input) in &changed {
+        tx.execute(
+            "UPDATE inputs SET input = ? WHERE profile = ? AND year = ? AND day = ?",
+            (input.as_str(), profile.as_str(), *year, *day),
+        )
+        .await?;
+    }
+    tx.commit().await?;
+    Ok(changed.len())
+}
+
+/// Decrypts a stored input like [`decrypt_input`], naming the input when that fails.
+fn decrypt(
+    stored: &str,
+    key: Option<&InputKey>,
+    profile: &str,
+    year: u16,
+    day: u8,
+) -> Result<Option<String>, String> {
+    decrypt_input(stored, key)
+        .map_err(|e| format!("The input for profile={profile}, year={year}, day={day}: {e}"))
+}
+
+fn no_key(profile: &str, year: u16, day: u8) -> String {
+    let (env, file) = (INPUT_KEY_ENV, INPUT_KEY_FILE_ENV);
+    format!(
+        "The input for profile={profile}, year={year}, day={day} is encrypted, set {env} or {file}"
+    )
+}
+
 async fn upsert_output(
     conn: &Connection,
+    profile: &str,
     year: u16,
     day: u8,
     part: u8,
     output: &str,
 ) -> Result<(), libsql::Error> {
     conn.execute(
-        "INSERT INTO solutions (year, day, part, input, output) VALUES (?, ?, ?, '', ?)
-         ON CONFLICT(year, day, part) DO UPDATE SET output = excluded.output",
-        (year, day, part, output),
+        "INSERT INTO answers (profile, year, day, part, output) VALUES (?, ?, ?, ?, ?)
+         ON CONFLICT(profile, year, day, part) DO UPDATE SET output = excluded.output",
+        (profile, year, day, part, output),
     )
     .await?;
     Ok(())
 }
 
-async fn read_solution(
+async fn read_answer(
     conn: &Connection,
+    profile: &str,
     year: u16,
     day: u8,
     part: u8,
 ) -> Result<String, Box<dyn std::error::Error>> {
+    find_answer(conn, profile, year, day, part)
+        .await?
+        .ok_or_else(|| {
+            format!("No answer found for profile={profile}, year={year}, day={day}, part={part}")
+                .into()
+        })
+}
+
+/// The stored answer of a puzzle, `None` when there is no row for it.
+async fn find_answer(
+    conn: &Connection,
+    profile: &str,
+    year: u16,
+    day: u8,
+    part: u8,
+) -> Result<Option<String>, libsql::Error> {
     let mut rows = conn
         .query(
-            "SELECT output FROM solutions WHERE year = ? AND day = ? AND part = ?",
-            (year, day, part),
+            "SELECT output FROM answers WHERE profile = ? AND year = ? AND day = ? AND part = ?",
+            (profile, year, day, part),
         )
         .await?;
-    if let Some(row) = rows.next().await? {
-        Ok(row.get::<String>(0)?)
-    } else {
-        Err(format!("No solution found for year={year}, day={day}, part={part}").into())
+    match rows.next().await? {
+        Some(row) => Ok(Some(row.get(0)?)),
+        None => Ok(None),
     }
 }
 
 async fn read_input(
     conn: &Connection,
+    profile: &str,
     year: u16,
     day: u8,
-    part: u8,
 ) -> Result<String, Box<dyn std::error::Error>> {
+    find_input(conn, profile, year, day).await?.ok_or_else(|| {
+        format!("No input found for profile={profile}, year={year}, day={day}").into()
+    })
+}
+
+/// The stored input of a puzzle, `None` when there is no row for it.
+async fn find_input(
+    conn: &Connection,
+    profile: &str,
+    year: u16,
+    day: u8,
+) -> Result<Option<String>, libsql::Error> {
     let mut rows = conn
         .query(
-            "SELECT input FROM solutions WHERE year = ? AND day = ? AND part = ?",
-            (year, day, part),
+            "SELECT input FROM inputs WHERE profile = ? AND year = ? AND day = ?",
+            (profile, year, day),
+        )
+        .await?;
+    match rows.next().await? {
+        Some(row) => Ok(Some(row.get(0)?)),
+        None => Ok(None),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn upsert_example(
+    conn: &Connection,
+    year: u16,
+    day: u8,
+    part: u8,
+    label: &str,
+    input: &str,
+    output: &str,
+    params: &Params,
+) -> Result<(), libsql::Error> {
+    conn.execute(
+        "INSERT INTO examples (year, day, part, label, input, output, params)
+         VALUES (?, ?, ?, ?, ?, ?, ?)
+         ON CONFLICT(year, day, part, label) DO UPDATE
+         SET input = excluded.input, output = excluded.output, params = excluded.params",
+        (year, day, part, label, input, output, params.to_string()),
+    )
+    .await?;
+    Ok(())
+}
+
+async fn upsert_params(
+    conn: &Connection,
+    profile: &str,
+    year: u16,
+    day: u8,
+    params: &Params,
+) -> Result<(), libsql::Error> {
+    conn.execute(
+        "INSERT INTO inputs (profile, year, day, input, params) VALUES (?, ?, ?, '', ?)
+         ON CONFLICT(profile, year, day) DO UPDATE SET params = excluded.params",
+        (profile, year, day, params.to_string()),
+    )
+    .await?;
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn upsert_guess(
+    conn: &Connection,
+    profile: &str,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+    verdict: Verdict,
+    at: Option<&str>,
+) -> Result<(), libsql::Error> {
+    conn.execute(
+        "INSERT INTO guesses (profile, year, day, part, answer, verdict, guessed_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, COALESCE(?7, CURRENT_TIMESTAMP))
+         ON CONFLICT(profile, year, day, part, answer) DO UPDATE
+         SET verdict = excluded.verdict, guessed_at = COALESCE(?7, guessed_at)",
+        (profile, year, day, part, answer, verdict.as_str(), at),
+    )
+    .await?;
+    Ok(())
+}
+
+/// When AoC unlocks a puzzle: midnight EST, in UTC as stored in `puzzles.released_at`.
+fn release_time(year: u16, day: u8) -> String {
+    format!("{year}-12-{day:02} 05:00:00")
+}
+
+/// Creates or updates a puzzle, leaving the fields that are `None` as they were.
+async fn upsert_puzzle(
+    conn: &Connection,
+    year: u16,
+    day: u8,
+    title: Option<&str>,
+    released_at: Option<&str>,
+    notes: Option<&str>,
+) -> Result<(), libsql::Error> {
+    conn.execute(
+        "INSERT INTO puzzles (year, day, title, released_at, notes)
+         VALUES (?1, ?2, COALESCE(?3, ''), COALESCE(?4, ?6), COALESCE(?5, ''))
+         ON CONFLICT(year, day) DO UPDATE
+         SET title = COALESCE(?3, title), released_at = COALESCE(?4, released_at),
+             notes = COALESCE(?5, notes)",
+        (
+            year,
+            day,
+            title,
+            released_at,
+            notes,
+            release_time(year, day),
+        ),
+    )
+    .await?;
+    Ok(())
+}
+
+async fn tag_puzzle(
+    conn: &Connection,
+    year: u16,
+    day: u8,
+    tags: &[String],
+    untags: &[String],
+) -> Result<(), libsql::Error> {
+    for tag in tags {
+        conn.execute(
+            "INSERT INTO puzzle_tags (year, day, tag) VALUES (?, ?, ?) ON CONFLICT DO NOTHING",
+            (year, day, tag.as_str()),
+        )
+        .await?;
+    }
+    for tag in untags {
+        conn.execute(
+            "DELETE FROM puzzle_tags WHERE year = ? AND day = ? AND tag = ?",
+            (year, day, tag.as_str()),
         )
         .await?;
-    if let Some(row) = rows.next().await? {
-        Ok(row.get::<String>(0)?)
-    } else {
-        Err(format!("No input found for year={year}, day={day}, part={part}").into())
     }
+    Ok(())
 }
 
-async fn delete_solution(conn: &Connection, year: u16, day: u8, part: u8) -> Result<(), libsql::Error> {
+/// Records when the profile completed a part, at `at` or else now, keeping an earlier time unless
+/// `at` is given.
+async fn mark_completed(
+    conn: &Connection,
+    profile: &str,
+    year: u16,
+    day: u8,
+    part: u8,
+    at: Option<&str>,
+) -> Result<(), libsql::Error> {
     conn.execute(
-        "DELETE FROM solutions WHERE year = ? AND day = ? AND part = ?",
-        (year, day, part),
+        "INSERT INTO completions (profile, year, day, part, completed_at)
+         VALUES (?1, ?2, ?3, ?4, COALESCE(?5, CURRENT_TIMESTAMP))
+         ON CONFLICT(profile, year, day, part) DO UPDATE
+         SET completed_at = COALESCE(?5, completed_at)",
+        (profile, year, day, part, at),
     )
     .await?;
     Ok(())
 }
 
-async fn list_solutions(conn: &Connection) -> Result<(), libsql::Error> {
+/// Why `answer` cannot be right, judging by the earlier guesses: it was already rejected, or it
+/// lies outside the bounds set by the guesses that were too high or too low.
+async fn check_guesses(
+    conn: &Connection,
+    profile: &str,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Option<String>, libsql::Error> {
     let mut rows = conn
         .query(
-            "SELECT year, day, part, output FROM solutions ORDER BY year, day, part",
-            (),
+            "SELECT answer, verdict FROM guesses
+             WHERE profile = ? AND year = ? AND day = ? AND part = ?",
+            (profile, year, day, part),
         )
         .await?;
+    let number = answer.parse::<i128>().ok();
     while let Some(row) = rows.next().await? {
-        let year: u16 = row.get::<u32>(0)? as u16;
-        let day: u8 = row.get::<u32>(1)? as u8;
-        let part: u8 = row.get::<u32>(2)? as u8;
-        let output: String = row.get(3)?;
-        println!("{year}\t{day}\t{part}\t{output}");
+        let guess: String = row.get(0)?;
+        let verdict: String = row.get(1)?;
+        let compared = number
+            .zip(guess.parse::<i128>().ok())
+            .map(|(n, bound)| n.cmp(&bound));
+        let warning = match (verdict.as_str(), compared) {
+            ("correct", _) => continue,
+            _ if guess == answer => format!(
+                "{answer} was already guessed ({})",
+                verdict.replace('_', " ")
+            ),
+            ("too_high", Some(Ordering::Greater)) => {
+                format!("{answer} is above {guess}, which is too high")
+            }
+            ("too_low", Some(Ordering::Less)) => {
+                format!("{answer} is below {guess}, which is too low")
+            }
+            _ => continue,
+        };
+        return Ok(Some(warning));
+    }
+    Ok(None)
+}
+
+async fn read_params(
+    conn: &Connection,
+    profile: &str,
+    year: u16,
+    day: u8,
+) -> Result<Params, Box<dyn std::error::Error>> {
+    let mut rows = conn
+        .query(
+            "SELECT params FROM inputs WHERE profile = ? AND year = ? AND day = ?",
+            (profile, year, day),
+        )
+        .await?;
+    match rows.next().await? {
+        Some(row) => Ok(row.get::<String>(0)?.parse()?),
+        None => Ok(Params::default()),
+    }
+}
+
+async fn read_example(
+    conn: &Connection,
+    year: u16,
+    day: u8,
+    part: u8,
+    label: &str,
+) -> Result<(String, String), Box<dyn std::error::Error>> {
+    let mut rows = conn
+        .query(
+            "SELECT input, output FROM examples
+             WHERE year = ? AND day = ? AND part = ? AND label = ?",
+            (year, day, part, label),
+        )
+        .await?;
+    if let Some(row) = rows.next().await? {
+        Ok((row.get::<String>(0)?, row.get::<String>(1)?))
+    } else {
+        Err(
+            format!("No example found for year={year}, day={day}, part={part}, label={label:?}")
+                .into(),
+        )
+    }
+}
+
+async fn delete_answer(
+    conn: &Connection,
+    profile: &str,
+    year: u16,
+    day: u8,
+    part: u8,
+) -> Result<(), libsql::Error> {
+    conn.execute(
+        "DELETE FROM answers WHERE profile = ? AND year = ? AND day = ? AND part = ?",
+        (profile, year, day, part),
+    )
+    .await?;
+    Ok(())
+}
+
+async fn delete_input(
+    conn: &Connection,
+    profile: &str,
+    year: u16,
+    day: u8,
+) -> Result<(), libsql::Error> {
+    conn.execute(
+        "DELETE FROM answers WHERE profile = ? AND year = ? AND day = ?",
+        (profile, year, day),
+    )
+    .await?;
+    conn.execute(
+        "DELETE FROM inputs WHERE profile = ? AND year = ? AND day = ?",
+        (profile, year, day),
+    )
+    .await?;
+    Ok(())
+}
+
+/// Lists the answered or completed parts with their puzzle's title, tags and completion time.
+async fn list_answers(
+    conn: &Connection,
+    profile: &str,
+    tag: Option<&str>,
+) -> Result<(), libsql::Error> {
+    let mut rows = conn
+        .query(
+            "SELECT p.year, p.day, p.part, a.output, z.title,
+                (SELECT group_concat(t.tag, ',') FROM puzzle_tags t
+                 WHERE t.year = p.year AND t.day = p.day),
+                c.completed_at
+             FROM (SELECT year, day, part FROM answers WHERE profile = ?1
+                   UNION SELECT year, day, part FROM completions WHERE profile = ?1) p
+             LEFT JOIN answers a
+               ON a.profile = ?1 AND a.year = p.year AND a.day = p.day AND a.part = p.part
+             LEFT JOIN completions c
+               ON c.profile = ?1 AND c.year = p.year AND c.day = p.day AND c.part = p.part
+             LEFT JOIN puzzles z ON z.year = p.year AND z.day = p.day
+             WHERE ?2 IS NULL
+                OR EXISTS (SELECT 1 FROM puzzle_tags t
+                           WHERE t.year = p.year AND t.day = p.day AND t.tag = ?2)
+             ORDER BY p.year, p.day, p.part",
+            (profile, tag),
+        )
+        .await?;
+    while let Some(row) = rows.next().await? {
+        let year: u16 = row.get::<u32>(0)? as u16;
+        let day: u8 = row.get::<u32>(1)? as u8;
+        let part: u8 = row.get::<u32>(2)? as u8;
+        let output: Option<String> = row.get(3)?;
+        let title: Option<String> = row.get(4)?;
+        let tags: Option<String> = row.get(5)?;
+        let completed_at: Option<String> = row.get(6)?;
+        println!(
+            "{year}\t{day}\t{part}\t{}\t{}\t{}\t{}",
+            output.as_deref().unwrap_or("-"),
+            title.as_deref().filter(|t| !t.is_empty()).unwrap_or("-"),
+            tags.as_deref().unwrap_or("-"),
+            completed_at.as_deref().unwrap_or("-"),
+        );
+    }
+    Ok(())
+}
+
+async fn list_puzzles(conn: &Connection, tag: Option<&str>) -> Result<(), libsql::Error> {
+    let mut rows = conn
+        .query(
+            "SELECT z.year, z.day, z.title, z.released_at,
+                (SELECT group_concat(t.tag, ',') FROM puzzle_tags t
+                 WHERE t.year = z.year AND t.day = z.day),
+                z.notes
+             FROM puzzles z
+             WHERE ?1 IS NULL
+                OR EXISTS (SELECT 1 FROM puzzle_tags t
+                           WHERE t.year = z.year AND t.day = z.day AND t.tag = ?1)
+             ORDER BY z.year, z.day",
+            [tag],
+        )
+        .await?;
+    while let Some(row) = rows.next().await? {
+        let year: u16 = row.get::<u32>(0)? as u16;
+        let day: u8 = row.get::<u32>(1)? as u8;
+        let title: String = row.get(2)?;
+        let released_at: String = row.get(3)?;
+        let tags: Option<String> = row.get(4)?;
+        let notes: String = row.get(5)?;
+        let title = if title.is_empty() { "-" } else { &title };
+        println!(
+            "{year}\t{day}\t{title}\t{released_at}\t{}\t{notes}",
+            tags.as_deref().unwrap_or("-")
+        );
+    }
+    Ok(())
+}
+
+async fn list_examples(
+    conn: &Connection,
+    year: Option<u16>,
+    day: Option<u8>,
+) -> Result<(), libsql::Error> {
+    let mut rows = conn
+        .query(
+            "SELECT year, day, part, label, output, params FROM examples
+             WHERE (?1 IS NULL OR year = ?1) AND (?2 IS NULL OR day = ?2)
+             ORDER BY year, day, part, label",
+            (year, day),
+        )
+        .await?;
+    while let Some(row) = rows.next().await? {
+        let year: u16 = row.get::<u32>(0)? as u16;
+        let day: u8 = row.get::<u32>(1)? as u8;
+        let part: u8 = row.get::<u32>(2)? as u8;
+        let label: String = row.get(3)?;
+        let output: String = row.get(4)?;
+        let params: String = row.get(5)?;
+        println!("{year}\t{day}\t{part}\t{label}\t{output}\t{params}");
+    }
+    Ok(())
+}
+
+async fn list_guesses(
+    conn: &Connection,
+    profile: &str,
+    year: Option<u16>,
+    day: Option<u8>,
+) -> Result<(), libsql::Error> {
+    let mut rows = conn
+        .query(
+            "SELECT year, day, part, answer, verdict, guessed_at FROM guesses
+             WHERE profile = ?1 AND (?2 IS NULL OR year = ?2) AND (?3 IS NULL OR day = ?3)
+             ORDER BY year, day, part, guessed_at",
+            (profile, year, day),
+        )
+        .await?;
+    while let Some(row) = rows.next().await? {
+        let year: u16 = row.get::<u32>(0)? as u16;
+        let day: u8 = row.get::<u32>(1)? as u8;
+        let part: u8 = row.get::<u32>(2)? as u8;
+        let answer: String = row.get(3)?;
+        let verdict: String = row.get(4)?;
+        let guessed_at: String = row.get(5)?;
+        println!("{year}\t{day}\t{part}\t{answer}\t{verdict}\t{guessed_at}");
+    }
+    Ok(())
+}
+
+async fn list_profiles(conn: &Connection) -> Result<(), libsql::Error> {
+    let mut rows = conn
+        .query(
+            "SELECT profile, COUNT(*) FROM inputs WHERE input != ''
+             GROUP BY profile ORDER BY profile",
+            (),
+        )
+        .await?;
+    while let Some(row) = rows.next().await? {
+        let profile: String = row.get(0)?;
+        let inputs: u32 = row.get(1)?;
+        println!("{profile}\t{inputs}");
+    }
+    Ok(())
+}
+
+/// Connects to the database of runs, see [`DbConfig::resolve_runs`], bringing it up to date.
+async fn connect_runs() -> Result<Connection, Box<dyn std::error::Error>> {
+    let conn = DbConfig::resolve_runs()?.open().await?.connect()?;
+    migrate_runs(&conn).await?;
+    Ok(conn)
+}
+
+/// What a day directory of `db export` holds besides `input.txt` and the `p{part}.answer` files, as
+/// `day.toml`
+#[derive(Default, Serialize, Deserialize)]
+struct DayFile {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    title: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    released_at: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    notes: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    params: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    examples: Vec<ExampleEntry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    guesses: Vec<GuessEntry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    completed: Vec<CompletedEntry>,
+}
+
+impl DayFile {
+    fn is_empty(&self) -> bool {
+        [&self.title, &self.released_at, &self.notes, &self.params]
+            .iter()
+            .all(|s| s.is_empty())
+            && self.tags.is_empty()
+            && self.examples.is_empty()
+            && self.guesses.is_empty()
+            && self.completed.is_empty()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct ExampleEntry {
+    part: u8,
+    #[serde(default)]
+    label: String,
+    output: String,
+    #[serde(default)]
+    params: String,
+    /// File holding the example input, next to `day.toml`
+    input: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct GuessEntry {
+    part: u8,
+    answer: String,
+    verdict: Verdict,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    at: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CompletedEntry {
+    part: u8,
+    at: String,
+}
+
+/// A day gathered from the database for `db export`
+#[derive(Default)]
+struct DayExport {
+    input: String,
+    answers: Vec<(u8, String)>,
+    example_inputs: Vec<(String, String)>,
+    file: DayFile,
+}
+
+/// Writes every day with data as `{year}/{day:02}/`, returning how many there were.
+/// Runs and bench results are specific to a machine and stay out.
+async fn export_db(
+    conn: &Connection,
+    profile: &str,
+    dir: &Path,
+    key: Option<&InputKey>,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    let mut days: BTreeMap<(u16, u8), DayExport> = BTreeMap::new();
+    let puzzle = |row: &libsql::Row| -> libsql::Result<(u16, u8)> {
+        Ok((row.get::<u32>(0)? as u16, row.get::<u32>(1)? as u8))
+    };
+
+    let mut rows = conn
+        .query(
+            "SELECT year, day, input, params FROM inputs WHERE profile = ?",
+            [profile],
+        )
+        .await?;
+    while let Some(row) = rows.next().await? {
+        let (year, day) = puzzle(&row)?;
+        let stored: String = row.get(2)?;
+        let export = days.entry((year, day)).or_default();
+        export.input =
+            decrypt(&stored, key, profile, year, day)?.ok_or_else(|| no_key(profile, year, day))?;
+        export.file.params = row.get(3)?;
+    }
+
+    let mut rows = conn
+        .query(
+            "SELECT year, day, part, output FROM answers WHERE profile = ?",
+            [profile],
+        )
+        .await?;
+    while let Some(row) = rows.next().await? {
+        days.entry(puzzle(&row)?)
+            .or_default()
+            .answers
+            .push((row.get::<u32>(2)? as u8, row.get(3)?));
+    }
+
+    let mut rows = conn
+        .query(
+            "SELECT year, day, part, label, input, output, params FROM examples
+             ORDER BY part, label",
+            (),
+        )
+        .await?;
+    while let Some(row) = rows.next().await? {
+        let day = days.entry(puzzle(&row)?).or_default();
+        let (part, label): (u8, String) = (row.get::<u32>(2)? as u8, row.get(3)?);
+        let file = example_file(part, &label, &day.example_inputs);
+        day.example_inputs.push((file.clone(), row.get(4)?));
+        day.file.examples.push(ExampleEntry {
+            part,
+            label,
+            output: row.get(5)?,
+            params: row.get(6)?,
+            input: file,
+        });
+    }
+
+    let mut rows = conn
+        .query(
+            "SELECT year, day, part, answer, verdict, guessed_at FROM guesses WHERE profile = ?
+             ORDER BY part, guessed_at",
+            [profile],
+        )
+        .await?;
+    while let Some(row) = rows.next().await? {
+        let verdict: String = row.get(4)?;
+        let verdict =
+            Verdict::from_db(&verdict).ok_or_else(|| format!("unknown verdict '{verdict}'"))?;
+        let guess = GuessEntry {
+            part: row.get::<u32>(2)? as u8,
+            answer: row.get(3)?,
+            verdict,
+            at: row.get(5)?,
+        };
+        days.entry(puzzle(&row)?)
+            .or_default()
+            .file
+            .guesses
+            .push(guess);
+    }
+
+    let mut rows = conn
+        .query(
+            "SELECT year, day, title, released_at, notes FROM puzzles",
+            (),
+        )
+        .await?;
+    while let Some(row) = rows.next().await? {
+        let file = &mut days.entry(puzzle(&row)?).or_default().file;
+        (file.title, file.released_at, file.notes) = (row.get(2)?, row.get(3)?, row.get(4)?);
+    }
+
+    let mut rows = conn
+        .query("SELECT year, day, tag FROM puzzle_tags ORDER BY tag", ())
+        .await?;
+    while let Some(row) = rows.next().await? {
+        days.entry(puzzle(&row)?)
+            .or_default()
+            .file
+            .tags
+            .push(row.get(2)?);
+    }
+
+    let mut rows = conn
+        .query(
+            "SELECT year, day, part, completed_at FROM completions WHERE profile = ? ORDER BY part",
+            [profile],
+        )
+        .await?;
+    while let Some(row) = rows.next().await? {
+        let completed = CompletedEntry {
+            part: row.get::<u32>(2)? as u8,
+            at: row.get(3)?,
+        };
+        days.entry(puzzle(&row)?)
+            .or_default()
+            .file
+            .completed
+            .push(completed);
+    }
+
+    for ((year, day), export) in &days {
+        let day_dir = dir.join(year.to_string()).join(format!("{day:02}"));
+        fs::create_dir_all(&day_dir)?;
+        if !export.input.is_empty() {
+            fs::write(day_dir.join("input.txt"), &export.input)?;
+        }
+        for (part, answer) in &export.answers {
+            fs::write(day_dir.join(format!("p{part}.answer")), answer)?;
+        }
+        for (file, input) in &export.example_inputs {
+            fs::write(day_dir.join(file), input)?;
+        }
+        if !export.file.is_empty() {
+            fs::write(day_dir.join("day.toml"), toml::to_string(&export.file)?)?;
+        }
+    }
+    Ok(days.len())
+}
+
+/// The name of the file holding an example's input in `db export`, the label kept to what is safe
+/// in a file name, and numbered when that is already `taken`.
+fn example_file(part: u8, label: &str, taken: &[(String, String)]) -> String {
+    let label: String = label
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => c,
+            _ => '_',
+        })
+        .collect();
+    let base = match label.as_str() {
+        "" => format!("example-p{part}"),
+        label => format!("example-p{part}-{label}"),
+    };
+    let mut file = format!("{base}.txt");
+    for n in 2.. {
+        if !taken.iter().any(|(f, _)| *f == file) {
+            break;
+        }
+        file = format!("{base}-{n}.txt");
+    }
+    file
+}
+
+/// Reads a file of `db import`, `None` when there is none.
+fn read_optional(path: &Path) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("{}: {e}", path.display()).into()),
+    }
+}
+
+/// Upserts the `{year}/{day}/` directories found under `dir`, returning how many there were.
+async fn import_db(
+    conn: &Connection,
+    profile: &str,
+    dir: &Path,
+    key: Option<&InputKey>,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    // Entries that are not numbered, like a README or `.git`, are not ours
+    let numbered = |dir: &Path| -> io::Result<Vec<(u16, PathBuf)>> {
+        let mut entries: Vec<_> = fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| Some((entry.file_name().to_str()?.parse().ok()?, entry.path())))
+            .collect();
+        entries.sort();
+        Ok(entries)
+    };
+
+    let mut days = 0;
+    for (year, year_dir) in numbered(dir)? {
+        for (day, day_dir) in numbered(&year_dir)? {
+            let day = u8::try_from(day)?;
+            days += 1;
+
+            if let Some(input) = read_optional(&day_dir.join("input.txt"))? {
+                upsert_input(conn, profile, year, day, &input, key).await?;
+            }
+            for part in 1..=2 {
+                // An editor may end the file with a newline, which is not part of the answer
+                if let Some(answer) = read_optional(&day_dir.join(format!("p{part}.answer")))? {
+                    upsert_output(conn, profile, year, day, part, answer.trim()).await?;
+                }
+            }
+
+            let path = day_dir.join("day.toml");
+            let file: DayFile = match read_optional(&path)? {
+                Some(content) => {
+                    toml::from_str(&content).map_err(|e| format!("{}: {e}", path.display()))?
+                }
+                None => DayFile::default(),
+            };
+            if !file.params.is_empty() {
+                upsert_params(conn, profile, year, day, &file.params.parse()?).await?;
+            }
+            for ExampleEntry {
+                part,
+                label,
+                output,
+                params,
+                input,
+            } in &file.examples
+            {
+                // Only ever a file next to `day.toml`, as `db export` writes it
+                if Path::new(input).file_name() != Some(OsStr::new(input)) {
+                    return Err(
+                        format!("{}: invalid example file '{input}'", path.display()).into(),
+                    );
+                }
+                let input = day_dir.join(input);
+                let data =
+                    fs::read_to_string(&input).map_err(|e| format!("{}: {e}", input.display()))?;
+                upsert_example(
+                    conn,
+                    year,
+                    day,
+                    *part,
+                    label,
+                    &data,
+                    output,
+                    &params.parse()?,
+                )
+                .await?;
+            }
+            fn given(value: &str) -> Option<&str> {
+                Some(value).filter(|v| !v.is_empty())
+            }
+            for GuessEntry {
+                part,
+                answer,
+                verdict,
+                at,
+            } in &file.guesses
+            {
+                let (part, verdict) = (*part, *verdict);
+                upsert_guess(conn, profile, year, day, part, answer, verdict, given(at)).await?;
+            }
+            let (title, released_at, notes) = (
+                given(&file.title),
+                given(&file.released_at),
+                given(&file.notes),
+            );
+            if title.or(released_at).or(notes).is_some() || !file.tags.is_empty() {
+                upsert_puzzle(conn, year, day, title, released_at, notes).await?;
+                tag_puzzle(conn, year, day, &file.tags, &[]).await?;
+            }
+            for CompletedEntry { part, at } in &file.completed {
+                mark_completed(conn, profile, year, day, *part, Some(at)).await?;
+            }
+        }
+    }
+    Ok(days)
+}
+
+/// The commit checked out in the crate, suffixed with `-dirty` when tracked files have changes.
+fn git_commit() -> Option<String> {
+    let git = |args: &[&str]| {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    let commit = git(&["rev-parse", "HEAD"])?;
+    let dirty = !git(&["status", "--porcelain", "--untracked-files=no"])?.is_empty();
+    Some(if dirty {
+        format!("{commit}-dirty")
+    } else {
+        commit
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn record_run(
+    conn: &Connection,
+    profile: &str,
+    solution: &dyn Solution,
+    commit: &str,
+    answer: Option<&str>,
+    elapsed: Duration,
+    status: &str,
+) -> Result<(), libsql::Error> {
+    conn.execute(
+        "INSERT INTO runs
+           (profile, year, day, part, variant, git_commit, answer, wall_time_ns, status)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        (
+            profile,
+            solution.year(),
+            solution.day(),
+            solution.part(),
+            solution.variant().unwrap_or(""),
+            commit,
+            answer,
+            elapsed.as_nanos() as i64,
+            status,
+        ),
+    )
+    .await?;
+    Ok(())
+}
+
+async fn print_history(
+    conn: &Connection,
+    profile: &str,
+    year: u16,
+    day: u8,
+    part: u8,
+    variant: Option<&str>,
+) -> Result<(), libsql::Error> {
+    let mut rows = conn
+        .query(
+            "SELECT ran_at, git_commit, variant, answer, wall_time_ns, status FROM runs
+             WHERE profile = ?1 AND year = ?2 AND day = ?3 AND part = ?4
+               AND (?5 IS NULL OR variant = ?5)
+             ORDER BY id",
+            (
+                profile,
+                year,
+                day,
+                part,
+                variant.map(|v| if v == "default" { "" } else { v }),
+            ),
+        )
+        .await?;
+    while let Some(row) = rows.next().await? {
+        let ran_at: String = row.get(0)?;
+        let commit: String = row.get(1)?;
+        let variant: String = row.get(2)?;
+        let answer: Option<String> = row.get(3)?;
+        let elapsed = Duration::from_nanos(row.get::<i64>(4)? as u64);
+        let status: String = row.get(5)?;
+
+        let dirty = if commit.ends_with("-dirty") {
+            "-dirty"
+        } else {
+            ""
+        };
+        let commit = commit.get(..10).map(|short| format!("{short}{dirty}"));
+        let variant = if variant.is_empty() {
+            "default"
+        } else {
+            &variant
+        };
+        println!(
+            "{ran_at}\t{}\t{variant}\t{}\t{elapsed:?}\t{status}",
+            commit.as_deref().unwrap_or("-"),
+            answer.as_deref().unwrap_or("-"),
+        );
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run_solutions(
+    conn: &Connection,
+    runs: Option<&Connection>,
+    profile: &str,
+    key: Option<&InputKey>,
+    year: Option<Span>,
+    day: Option<Span>,
+    part: Option<Span>,
+    variant: Option<&str>,
+    overrides: Option<Params>,
+    input: Option<String>,
+    examples: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut solutions: Vec<&dyn Solution> = inventory::iter::<&dyn Solution>
+        .into_iter()
+        .copied()
+        .filter(|s| {
+            year.is_none_or(|y| y.contains(s.year()))
+                && day.is_none_or(|d| d.contains(s.day() as u16))
+                && part.is_none_or(|p| p.contains(s.part() as u16))
+                && variant.is_none_or(|v| v == s.variant().unwrap_or("default"))
+        })
+        .collect();
+
+    let compiled_out: Vec<&CompiledOut> = COMPILED_OUT
+        .iter()
+        .filter(|c| {
+            year.is_none_or(|y| y.contains(c.year))
+                && day.is_none_or(|d| d.contains(c.day as u16))
+                && part.is_none_or(|p| p.contains(c.part as u16))
+                && variant.is_none_or(|v| v == c.variant.unwrap_or("default"))
+        })
+        .collect();
+
+    if solutions.is_empty() && compiled_out.is_empty() {
+        return Err("No solutions match the given filters".into());
+    }
+
+    solutions.sort_by_key(|s| (s.year(), s.day(), s.part(), s.variant()));
+
+    if let Some(overrides) = &overrides {
+        for (name, _) in overrides.iter() {
+            if !solutions
+                .iter()
+                .any(|s| s.params().iter().any(|(n, _)| *n == name))
+            {
+                return Err(format!("No selected solution declares the parameter '{name}'").into());
+            }
+        }
+    }
+
+    let limits = Limits::resolve()?;
+
+    // The examples of every part come before any input, which is usually much slower
+    if examples {
+        for &solution in &solutions {
+            check_examples(conn, solution, &limits).await?;
+        }
+    }
+
+    let mut failures = 0;
+    let commit = git_commit().unwrap_or_default();
+
+    for solution in solutions {
+        let (year, day, part) = (solution.year(), solution.day(), solution.part());
+        let label = part_label(part, solution.variant());
+
+        // Only runs on the stored input and parameters are comparable enough to go in the history
+        let mut stored = input.is_none();
+        let (data, mut expected, mut params) = match &input {
+            Some(data) => (Some(data.clone()), None, Params::default()),
+            None => {
+                let stored = find_input(conn, profile, year, day)
+                    .await?
+                    .unwrap_or_default();
+                let expected = find_answer(conn, profile, year, day, part).await?;
+                (
+                    decrypt(&stored, key, profile, year, day)?,
+                    expected,
+                    read_params(conn, profile, year, day).await?,
+                )
+            }
+        };
+        for (name, value) in overrides.iter().flat_map(Params::iter) {
+            if solution.params().iter().any(|(n, _)| *n == name) {
+                params.set(name, value);
+                // The stored output only holds for the stored parameters
+                expected = None;
+                stored = false;
+            }
+        }
+
+        let Some(data) = data else {
+            println!("{year}\t{day}\t{label}\t-\t-\tencrypted input, no key");
+            continue;
+        };
+        if data.is_empty() {
+            println!("{year}\t{day}\t{label}\t-\t-\tno input");
+            continue;
+        }
+
+        // A solution that hangs, runs out of memory or panics only fails its own part
+        let (answer, elapsed, stages) = match solve_isolated(solution, &data, &params, &limits)? {
+            Solved::Returned {
+                result: Ok(answer),
+                parse,
+                elapsed,
+            } => {
+                let stages =
+                    parse.map(|parse| format!(" (parse {parse:?}, solve {:?})", elapsed - parse));
+                (answer, elapsed, stages.unwrap_or_default())
+            }
+            Solved::Returned {
+                result: Err(e),
+                elapsed,
+                ..
+            } => {
+                failures += 1;
+                if let Some(runs) = runs.filter(|_| stored) {
+                    record_run(runs, profile, solution, &commit, None, elapsed, "error").await?;
+                }
+                println!("{year}\t{day}\t{label}\t-\t{elapsed:?}\tERROR ({e})");
+                continue;
+            }
+            Solved::Stopped { outcome, elapsed } => {
+                failures += 1;
+                let (status, summary) = stopped(&outcome);
+                if let Some(runs) = runs.filter(|_| stored) {
+                    record_run(runs, profile, solution, &commit, None, elapsed, status).await?;
+                }
+                println!("{year}\t{day}\t{label}\t-\t{elapsed:?}\t{summary}");
+                continue;
+            }
+        };
+
+        let (outcome, status) = match expected.as_deref().map(str::trim) {
+            Some("") | None => ("unchecked", "-".to_string()),
+            Some(expected) if expected == answer.trim() => ("ok", "ok".to_string()),
+            Some(expected) => {
+                failures += 1;
+                ("fail", format!("FAIL (expected {expected})"))
+            }
+        };
+        if stored {
+            if let Some(runs) = runs {
+                let answer = Some(answer.trim());
+                record_run(runs, profile, solution, &commit, answer, elapsed, outcome).await?;
+            }
+            // Worth knowing before the answer is submitted, i.e. while there is no stored output
+            // yet
+            if let Some(warning) =
+                check_guesses(conn, profile, year, day, part, answer.trim()).await?
+            {
+                eprintln!("warning: {year} day {day} part {label}: {warning}");
+            }
+        }
+
+        println!(
+            "{year}\t{day}\t{label}\t{}\t{elapsed:?}{stages}\t{status}",
+            answer.trim()
+        );
+    }
+
+    for CompiledOut {
+        year,
+        day,
+        part,
+        variant,
+        cfg,
+    } in compiled_out
+    {
+        let label = part_label(*part, *variant);
+        println!("{year}\t{day}\t{label}\t-\t-\tcompiled out (needs {cfg})");
+    }
+
+    if failures > 0 {
+        return Err(
+            format!("{failures} solution(s) failed or did not match the stored output").into(),
+        );
+    }
+
+    Ok(())
+}
+
+fn part_label(part: u8, variant: Option<&str>) -> String {
+    match variant {
+        Some(variant) => format!("{part} ({variant})"),
+        None => part.to_string(),
+    }
+}
+
+/// Runs a solution on the stored examples of its part, failing at the first one it gets wrong.
+async fn check_examples(
+    conn: &Connection,
+    solution: &dyn Solution,
+    limits: &Limits,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (year, day, part) = (solution.year(), solution.day(), solution.part());
+    let label = part_label(part, solution.variant());
+    // Rows left empty by `new` are placeholders, not examples
+    let mut rows = conn
+        .query(
+            "SELECT label, input, output, params FROM examples
+             WHERE year = ? AND day = ? AND part = ? AND input != '' AND output != ''
+             ORDER BY label",
+            (year, day, part),
+        )
+        .await?;
+    while let Some(row) = rows.next().await? {
+        let name: String = row.get(0)?;
+        let input: String = row.get(1)?;
+        let expected: String = row.get(2)?;
+        let params: Params = row.get::<String>(3)?.parse()?;
+
+        let (answer, elapsed, status) = match solve_isolated(solution, &input, &params, limits)? {
+            Solved::Returned {
+                result: Ok(answer),
+                elapsed,
+                ..
+            } if answer.trim() == expected.trim() => (answer, elapsed, "ok".to_string()),
+            Solved::Returned {
+                result: Ok(answer),
+                elapsed,
+                ..
+            } => {
+                let status = format!("FAIL (expected {})", expected.trim());
+                (answer, elapsed, status)
+            }
+            Solved::Returned {
+                result: Err(e),
+                elapsed,
+                ..
+            } => ("-".to_string(), elapsed, format!("ERROR ({e})")),
+            Solved::Stopped { outcome, elapsed } => ("-".to_string(), elapsed, stopped(&outcome).1),
+        };
+        println!(
+            "{year}\t{day}\t{label}\t{}\t{elapsed:?}\texample {name:?}: {status}",
+            answer.trim()
+        );
+        if status != "ok" {
+            return Err(format!(
+                "{year} day {day} part {label} failed example {name:?}, the input was not run"
+            )
+            .into());
+        }
+    }
+    Ok(())
+}
+
+/// How a solution ended in the child process of [`solve_isolated`]
+enum Solved {
+    /// It returned an answer or an error, after `elapsed`, of which `parse` went to the parse of a
+    /// `#[day]`, as timed in the child
+    Returned {
+        result: Result<String, String>,
+        parse: Option<Duration>,
+        elapsed: Duration,
+    },
+    /// It did not return, see [`run_isolated_with_output`]
+    Stopped { outcome: Outcome, elapsed: Duration },
+}
+
+/// Runs a solution in a child process of its own, through the hidden `solve` command, within the
+/// limits of the tests.
+fn solve_isolated(
+    solution: &dyn Solution,
+    input: &str,
+    params: &Params,
+    limits: &Limits,
+) -> Result<Solved, Box<dyn std::error::Error>> {
+    let (reader, mut writer) = io::pipe()?;
+    let mut command = Command::new(std::env::current_exe()?);
+    command.args([
+        "solve".to_string(),
+        solution.year().to_string(),
+        solution.day().to_string(),
+        solution.part().to_string(),
+        format!("--params={params}"),
+    ]);
+    if let Some(variant) = solution.variant() {
+        command.args(["--variant", variant]);
+    }
+    command.stdin(reader);
+    // Written while the child reads it, as a pipe only holds so much; a child that stops early
+    // leaves the rest unread
+    let input = input.to_string();
+    let feed = std::thread::spawn(move || {
+        let _ = writer.write_all(input.as_bytes());
+    });
+
+    let start = Instant::now();
+    let (outcome, stdout) = run_isolated_with_output(command, limits)?;
+    let elapsed = start.elapsed();
+    let _ = feed.join();
+    Ok(match outcome {
+        Outcome::Passed => read_solved(&stdout).unwrap_or_else(|| Solved::Stopped {
+            outcome: Outcome::Crashed(format!("unexpected output {stdout:?}")),
+            elapsed,
+        }),
+        outcome => Solved::Stopped { outcome, elapsed },
+    })
+}
+
+/// Reads what [`solve_child`] printed.
+fn read_solved(stdout: &str) -> Option<Solved> {
+    let (head, payload) = stdout.split_once('\n')?;
+    let [status, parse, elapsed] = head.split(' ').collect::<Vec<_>>()[..] else {
+        return None;
+    };
+    let nanos = |n: &str| n.parse().ok().map(Duration::from_nanos);
+    let result = match status {
+        "ok" => Ok(payload.to_string()),
+        "error" => Err(payload.to_string()),
+        _ => return None,
+    };
+    Some(Solved::Returned {
+        result,
+        parse: nanos(parse),
+        elapsed: nanos(elapsed)?,
+    })
+}
+
+/// The status recorded for a solution that did not return, and how `run` shows it.
+fn stopped(outcome: &Outcome) -> (&'static str, String) {
+    // On one line, like the rest of the table
+    let reason = outcome.to_string();
+    let reason = reason.lines().map(str::trim).collect::<Vec<_>>().join(" ");
+    match outcome {
+        Outcome::TimedOut(_) => ("timeout", format!("TIMEOUT ({reason})")),
+        Outcome::OutOfMemory(_) => ("out_of_memory", format!("OUT OF MEMORY ({reason})")),
+        Outcome::Panicked(_) => ("panic", format!("PANIC ({reason})")),
+        Outcome::Passed | Outcome::Crashed(_) => ("crash", format!("CRASH ({reason})")),
+    }
+}
+
+/// Runs a solution on the input from stdin, printing for [`read_solved`] `ok` or `error`, the
+/// nanoseconds of the parse of a `#[day]` (`-` otherwise) and of the whole run, then the answer or
+/// error on the next lines.
+fn solve_child(
+    year: u16,
+    day: u8,
+    part: u8,
+    variant: Option<&str>,
+    params: &Params,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let solution = inventory::iter::<&dyn Solution>
+        .into_iter()
+        .find(|s| (s.year(), s.day(), s.part(), s.variant()) == (year, day, part, variant))
+        .ok_or_else(|| format!("No solution for year={year}, day={day}, part={part}"))?;
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+
+    let start = Instant::now();
+    let (result, parse) = match solution.staged() {
+        Some(staged) => match staged.try_parse(&input) {
+            Ok(parsed) => {
+                let parse = start.elapsed();
+                (staged.try_solve_parsed(&parsed, params), Some(parse))
+            }
+            Err(e) => (Err(e), None),
+        },
+        None => (solution.try_solve_with(&input, params), None),
+    };
+    let elapsed = start.elapsed().as_nanos();
+
+    let parse = parse.map_or("-".to_string(), |parse| parse.as_nanos().to_string());
+    match result {
+        Ok(answer) => print!("ok {parse} {elapsed}\n{answer}"),
+        Err(e) => print!("error {parse} {elapsed}\n{e}"),
+    }
+    Ok(())
+}
+
+/// One row of divan's results table
+struct BenchResult {
+    name: String,
+    profile: String,
+    median_ns: f64,
+    min_ns: f64,
+    max_ns: f64,
+    samples: u32,
+}
+
+/// Runs `cargo bench`, echoing its output, and stores the results under the current commit in
+/// the database of runs.
+async fn record_benches(
+    conn: &Connection,
+    config: &DbConfig,
+    filters: &[String],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let commit = git_commit().unwrap_or_default();
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut child = Command::new(cargo)
+        .args(["bench", "--bench", "bench", "--", "--color=never"])
+        .args(filters)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        // The benches read their inputs from the same database the results go to
+        .env(DB_ENV, config.to_string())
+        .stdout(Stdio::piped())
+        .spawn()?;
+
+    let mut results = Vec::new();
+    let mut path = Vec::new();
+    for line in BufReader::new(child.stdout.take().unwrap()).lines() {
+        let line = line?;
+        println!("{line}");
+        results.extend(parse_bench_line(&line, &mut path));
+    }
+    if !child.wait()?.success() {
+        return Err("cargo bench failed, no results recorded".into());
+    }
+
+    for result in &results {
+        conn.execute(
+            "INSERT INTO bench_results
+               (git_commit, name, profile, median_ns, min_ns, max_ns, samples)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+            (
+                commit.as_str(),
+                result.name.as_str(),
+                result.profile.as_str(),
+                result.median_ns,
+                result.min_ns,
+                result.max_ns,
+                result.samples,
+            ),
+        )
+        .await?;
+    }
+    println!("Recorded {} results for commit {commit}", results.len());
+    Ok(())
+}
+
+/// Reads a row of divan's results table, where `path` tracks the enclosing benches and groups:
+///
+/// ```text
+/// ╰─ y2025_d01_p1                │               │ ...
+///    ├─ alice      886.3 µs      │ 1.635 ms      │ 1.043 ms      │ 1.104 ms      │ 100     │ 100
+/// ```
+fn parse_bench_line(line: &str, path: &mut Vec<String>) -> Option<BenchResult> {
+    let row = line.trim_start_matches(['│', '├', '╰', '─', ' ']);
+    // Every level of the tree is indented by three characters
+    let depth = (line.chars().count() - row.chars().count()) / 3;
+    if depth == 0 || !row.contains('│') {
+        return None;
+    }
+
+    let columns: Vec<&str> = row.split('│').map(str::trim).collect();
+    let (name, fastest) = columns[0].split_once(' ').unwrap_or((columns[0], ""));
+    path.truncate(depth - 1);
+    path.push(name.to_string());
+
+    let [_, slowest, median, _mean, samples, ..] = columns[..] else {
+        return None;
+    };
+    Some(BenchResult {
+        name: path[0].clone(),
+        profile: path[1..].join("/"),
+        median_ns: parse_bench_time(median)?,
+        min_ns: parse_bench_time(fastest.trim())?,
+        max_ns: parse_bench_time(slowest)?,
+        samples: samples.parse().ok()?,
+    })
+}
+
+/// Reads a time printed by divan, such as `886.3 µs`, in nanoseconds.
+fn parse_bench_time(time: &str) -> Option<f64> {
+    let (value, unit) = time.split_once(' ')?;
+    let scale = match unit {
+        "ps" => 1e-3,
+        "ns" => 1.0,
+        "µs" => 1e3,
+        "ms" => 1e6,
+        "s" => 1e9,
+        "m" => 60e9,
+        "h" => 3600e9,
+        "d" => 86400e9,
+        _ => return None,
+    };
+    Some(value.parse::<f64>().ok()? * scale)
+}
+
+/// The full hash of a commit, tag or branch, or the name itself when git does not know it,
+/// e.g. a `-dirty` commit as recorded.
+fn resolve_commit(rev: &str) -> String {
+    let output = Command::new("git")
+        .args([
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("{rev}^{{commit}}"),
+        ])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output();
+    match output {
+        Ok(output) if output.status.success() => {
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        }
+        _ => rev.to_string(),
+    }
+}
+
+/// The latest median of every bench and profile recorded for a commit.
+async fn latest_medians(
+    conn: &Connection,
+    commit: &str,
+) -> Result<Vec<(String, String, f64)>, libsql::Error> {
+    let mut rows = conn
+        .query(
+            "SELECT name, profile, median_ns FROM bench_results
+             WHERE id IN (SELECT MAX(id) FROM bench_results WHERE git_commit = ?
+                          GROUP BY name, profile)
+             ORDER BY name, profile",
+            [commit],
+        )
+        .await?;
+    let mut medians = Vec::new();
+    while let Some(row) = rows.next().await? {
+        medians.push((row.get(0)?, row.get(1)?, row.get(2)?));
+    }
+    Ok(medians)
+}
+
+/// Whether a bench times a whole solution, like `y2025_d08_p1` or `y2025_d01_p2_naive`,
+/// rather than one stage of it, like `y2025_d08_parse` or `y2025_d08_p1_solve`.
+fn is_solution_bench(name: &str) -> bool {
+    let mut parts = name.split('_');
+    let mut numbered = |prefix: char| {
+        let number = parts
+            .next()
+            .and_then(|part| part.strip_prefix(prefix))
+            .unwrap_or_default();
+        !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit())
+    };
+    numbered('y') && numbered('d') && numbered('p') && !name.ends_with("_solve")
+}
+
+async fn compare_benches(
+    conn: &Connection,
+    baseline: &str,
+    against: Option<&str>,
+    threshold: f64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let baseline_commit = resolve_commit(baseline);
+    let current_commit = match against {
+        Some(rev) => resolve_commit(rev),
+        None => git_commit().ok_or("Not in a git checkout, pass --against")?,
+    };
+
+    let baseline_medians = latest_medians(conn, &baseline_commit).await?;
+    if baseline_medians.is_empty() {
+        return Err(format!("No bench results recorded for {baseline} ({baseline_commit})").into());
+    }
+    let current_medians = latest_medians(conn, &current_commit).await?;
+    if current_medians.is_empty() {
+        return Err(format!(
+            "No bench results recorded for {current_commit}, run `bench record` first"
+        )
+        .into());
+    }
+
+    let mut regressions = 0;
+    for (name, profile, current) in &current_medians {
+        let Some((_, _, base)) = baseline_medians
+            .iter()
+            .find(|(n, p, _)| n == name && p == profile)
+        else {
+            continue;
+        };
+        let change = (current / base - 1.0) * 100.0;
+        let status = if change > threshold && is_solution_bench(name) {
+            regressions += 1;
+            "REGRESSED"
+        } else {
+            "ok"
+        };
+        println!(
+            "{name}\t{profile}\t{:?}\t{:?}\t{change:+.1}%\t{status}",
+            Duration::from_nanos(*base as u64),
+            Duration::from_nanos(*current as u64),
+        );
+    }
+
+    if regressions > 0 {
+        return Err(
+            format!("{regressions} solution(s) regressed by more than {threshold}%").into(),
+        );
+    }
+    Ok(())
+}
+
+/// Fails unless the puzzle is out, going by its stored release time or else the usual one.
+async fn check_released(
+    conn: &Connection,
+    year: u16,
+    day: u8,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut rows = conn
+        .query(
+            "SELECT released_at, released_at <= CURRENT_TIMESTAMP
+             FROM (SELECT COALESCE(
+                           (SELECT released_at FROM puzzles WHERE year = ?1 AND day = ?2), ?3
+                         ) AS released_at)",
+            (year, day, release_time(year, day)),
+        )
+        .await?;
+    let row = rows.next().await?.ok_or("cannot read the release time")?;
+    let released_at: String = row.get(0)?;
+    if !row.get::<bool>(1)? {
+        return Err(format!("{year} day {day} is not out until {released_at} UTC").into());
+    }
+    Ok(())
+}
+
+/// Sleeps until the last request to the site is [`MIN_REQUEST_INTERVAL`] old, then logs a new one
+/// in the database of runs, which is not checked in.
+async fn throttle(
+    runs: &Connection,
+    profile: &str,
+    method: &str,
+    path: &str,
+) -> Result<i64, libsql::Error> {
+    let mut rows = runs
+        .query(
+            "SELECT (julianday('now') - julianday(MAX(requested_at))) * 86400 FROM requests",
+            (),
+        )
+        .await?;
+    let elapsed: Option<f64> = match rows.next().await? {
+        Some(row) => row.get(0)?,
+        None => None,
+    };
+    if let Some(elapsed) = elapsed {
+        let wait = MIN_REQUEST_INTERVAL.saturating_sub(Duration::from_secs_f64(elapsed.max(0.0)));
+        if !wait.is_zero() {
+            println!("Waiting {wait:.1?} between requests to the site");
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    runs.execute(
+        "INSERT INTO requests (profile, method, path) VALUES (?, ?, ?)",
+        (profile, method, path),
+    )
+    .await?;
+    Ok(runs.last_insert_rowid())
+}
+
+async fn record_response(
+    runs: &Connection,
+    request: i64,
+    status: u16,
+) -> Result<(), libsql::Error> {
+    runs.execute(
+        "UPDATE requests SET status = ? WHERE id = ?",
+        (status, request),
+    )
+    .await?;
+    Ok(())
+}
+
+/// Downloads the profile's input of a puzzle and stores it, unless one is stored already.
+async fn fetch_input(
+    conn: &Connection,
+    runs: &Connection,
+    profile: &str,
+    key: Option<&InputKey>,
+    year: u16,
+    day: u8,
+    force: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if !force
+        && !find_input(conn, profile, year, day)
+            .await?
+            .unwrap_or_default()
+            .is_empty()
+    {
+        println!(
+            "Input already stored: profile={profile}, year={year}, day={day} \
+             (use --force to fetch it again)"
+        );
+        return Ok(());
+    }
+    check_released(conn, year, day).await?;
+
+    let site = Site::resolve(profile)?;
+    let path = format!("/{year}/day/{day}/input");
+    let request = throttle(runs, profile, "GET", &path).await?;
+    let (status, body) = site.get(&path).await?;
+    record_response(runs, request, status).await?;
+    if status != 200 {
+        let reason = body.lines().next().unwrap_or_default();
+        return Err(format!("GET {} failed with {status}: {reason}", site.url(&path)).into());
+    }
+
+    upsert_input(conn, profile, year, day, &body, key).await?;
+    println!(
+        "Fetched input: profile={profile}, year={year}, day={day}, {} bytes",
+        body.len()
+    );
+    Ok(())
+}
+
+/// The answer of the reference solution of a part on the profile's stored input.
+async fn solve_stored(
+    conn: &Connection,
+    profile: &str,
+    key: Option<&InputKey>,
+    year: u16,
+    day: u8,
+    part: u8,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let variants: Vec<&dyn Solution> = inventory::iter::<&dyn Solution>
+        .into_iter()
+        .copied()
+        .filter(|s| s.year() == year && s.day() == day && s.part() == part)
+        .collect();
+    let solution = variants
+        .iter()
+        .find(|s| s.is_reference())
+        .or_else(|| variants.iter().find(|s| s.variant().is_none()))
+        .or(variants.first())
+        .ok_or_else(|| {
+            format!("No solution for year={year}, day={day}, part={part}, pass the answer instead")
+        })?;
+
+    let stored = read_input(conn, profile, year, day).await?;
+    let input =
+        decrypt(&stored, key, profile, year, day)?.ok_or_else(|| no_key(profile, year, day))?;
+    let params = read_params(conn, profile, year, day).await?;
+    let answer = solution.try_solve_with(&input, &params)?.trim().to_string();
+    println!("Answer: {answer}");
+    Ok(answer)
+}
+
+/// What the site made of a submitted answer
+enum Submission {
+    Verdict(Verdict),
+    /// Submitted too soon after the previous answer, with the wait the site asked for, e.g.
+    /// `4m 12s`
+    TooRecent(String),
+    /// The part is already solved, or the one before it is not
+    WrongLevel,
+}
+
+/// Reads the verdict out of the page the site answers a submission with.
+fn parse_submission(page: &str) -> Option<Submission> {
+    let text = page.to_lowercase();
+    if text.contains("that's the right answer") {
+        Some(Submission::Verdict(Verdict::Correct))
+    } else if text.contains("that's not the right answer") {
+        Some(Submission::Verdict(
+            if text.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if text.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            },
+        ))
+    } else if text.contains("you gave an answer too recently") {
+        // "... You have 4m 12s left to wait."
+        let wait = text
+            .split_once(" left to wait")
+            .and_then(|(before, _)| before.rsplit_once("you have "));
+        Some(Submission::TooRecent(
+            wait.map_or("a while".to_string(), |(_, wait)| wait.to_string()),
+        ))
+    } else if text.contains("you don't seem to be solving the right level") {
+        Some(Submission::WrongLevel)
+    } else {
+        None
+    }
+}
+
+/// Submits an answer, unless the part is completed or the earlier guesses already rule it out, and
+/// records the verdict.
+#[allow(clippy::too_many_arguments)]
+async fn submit_answer(
+    conn: &Connection,
+    runs: &Connection,
+    profile: &str,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+    force: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut rows = conn
+        .query(
+            "SELECT a.output FROM completions c
+             LEFT JOIN answers a
+               ON a.profile = c.profile AND a.year = c.year AND a.day = c.day AND a.part = c.part
+             WHERE c.profile = ? AND c.year = ? AND c.day = ? AND c.part = ?",
+            (profile, year, day, part),
+        )
+        .await?;
+    if let Some(row) = rows.next().await? {
+        let output: Option<String> = row.get(0)?;
+        let output = output.unwrap_or_default();
+        println!(
+            "Already completed: profile={profile}, year={year}, day={day}, part={part}, \
+             answer={output}"
+        );
+        return Ok(());
+    }
+    if let Some(reason) = check_guesses(conn, profile, year, day, part, answer).await?
+        && !force
+    {
+        return Err(format!("Not submitting: {reason} (use --force to submit anyway)").into());
+    }
+    check_released(conn, year, day).await?;
+
+    let site = Site::resolve(profile)?;
+    let path = format!("/{year}/day/{day}/answer");
+    let request = throttle(runs, profile, "POST", &path).await?;
+    let (status, page) = site
+        .post(&path, &[("level", &part.to_string()), ("answer", answer)])
+        .await?;
+    record_response(runs, request, status).await?;
+    if status != 200 {
+        return Err(format!("POST {} failed with {status}", site.url(&path)).into());
+    }
+
+    let verdict = match parse_submission(&page) {
+        Some(Submission::Verdict(verdict)) => verdict,
+        Some(Submission::TooRecent(wait)) => {
+            return Err(format!("Answer given too recently, wait {wait}").into());
+        }
+        Some(Submission::WrongLevel) => {
+            return Err(
+                format!("Part {part} is already solved, or the part before it is not").into(),
+            );
+        }
+        None => {
+            return Err(format!("Cannot read the verdict from POST {}", site.url(&path)).into());
+        }
+    };
+
+    upsert_guess(conn, profile, year, day, part, answer, verdict, None).await?;
+    let verdict = match verdict {
+        Verdict::Correct => {
+            upsert_output(conn, profile, year, day, part, answer).await?;
+            mark_completed(conn, profile, year, day, part, None).await?;
+            println!(
+                "Correct: profile={profile}, year={year}, day={day}, part={part}, answer={answer}"
+            );
+            return Ok(());
+        }
+        Verdict::Wrong => "wrong",
+        Verdict::TooHigh => "too high",
+        Verdict::TooLow => "too low",
+    };
+    Err(format!("Answer {answer} is {verdict}").into())
+}
+
+/// Inserts `line` into `content` before the first line `later` says sorts after it, or else after
+/// the last line `ours` matches, or at the end.
+fn insert_sorted(
+    content: &str,
+    line: &str,
+    ours: impl Fn(&str) -> bool,
+    later: impl Fn(&str) -> bool,
+) -> String {
+    let lines: Vec<&str> = content.lines().collect();
+    let at = match lines.iter().position(|l| later(l)) {
+        Some(at) => at,
+        None => lines
+            .iter()
+            .rposition(|l| ours(l))
+            .map_or(lines.len(), |at| at + 1),
+    };
+    let mut lines: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    lines.insert(at, line.to_string());
+    lines.join("\n") + "\n"
+}
+
+/// Declares a new year: its module in `src/lib.rs`, behind a `y{year}` feature that is on by
+/// default.
+fn add_year(root: &Path, year: u16) -> Result<(), Box<dyn std::error::Error>> {
+    let year_of = |line: &str| {
+        line.strip_prefix("#[cfg(feature = \"y")
+            .and_then(|l| l.get(..4)?.parse::<u16>().ok())
+    };
+    let path = root.join("src/lib.rs");
+    let lib = fs::read_to_string(&path)?;
+    let lib = insert_sorted(
+        &lib,
+        &format!("#[cfg(feature = \"y{year}\")]\nmod y{year};"),
+        |line| line.starts_with("mod y"),
+        |line| year_of(line).is_some_and(|y| y > year),
+    );
+    fs::write(&path, lib)?;
+
+    let path = root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&path)?;
+    let feature_year = |line: &str| {
+        line.strip_prefix('y')
+            .and_then(|l| l.strip_suffix(" = []")?.parse::<u16>().ok())
+    };
+    let manifest = insert_sorted(
+        &manifest,
+        &format!("y{year} = []"),
+        |line| feature_year(line).is_some(),
+        |line| feature_year(line).is_some_and(|y| y > year),
+    );
+    // The years come first in the default features, in order
+    let feature = format!("\"y{year}\"");
+    let manifest: Vec<String> = manifest
+        .lines()
+        .map(|line| {
+            match line
+                .strip_prefix("default = [")
+                .and_then(|l| l.strip_suffix(']'))
+            {
+                Some(features) => {
+                    let (mut years, others): (Vec<&str>, Vec<&str>) =
+                        features.split(", ").partition(|f| f.starts_with("\"y"));
+                    years.push(&feature);
+                    years.sort();
+                    format!("default = [{}]", [years, others].concat().join(", "))
+                }
+                None => line.to_string(),
+            }
+        })
+        .collect();
+    fs::write(&path, manifest.join("\n") + "\n")?;
+    Ok(())
+}
+
+/// Writes the module of a new puzzle from the template and creates its empty rows, refusing to
+/// touch an existing one.
+async fn scaffold_day(
+    conn: &Connection,
+    profile: &str,
+    year: u16,
+    day: u8,
+    template: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let module = root.join(format!("src/y{year}/d{day:02}.rs"));
+    let declared = inventory::iter::<&dyn Solution>
+        .into_iter()
+        .any(|s| s.year() == year && s.day() == day)
+        || COMPILED_OUT.iter().any(|c| c.year == year && c.day == day);
+    if declared || module.exists() {
+        return Err(format!("{year} day {day} already exists in {}", module.display()).into());
+    }
+
+    let template = match template {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?,
+        None => fs::read_to_string(root.join("templates/day.rs"))
+            .map_err(|e| format!("templates/day.rs: {e}"))?,
+    };
+    let code = template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string());
+
+    let year_file = root.join(format!("src/y{year}.rs"));
+    if !year_file.exists() {
+        add_year(root, year)?;
+        fs::write(&year_file, "")?;
+        println!("Added feature y{year}, on by default");
+    }
+    let mods = insert_sorted(
+        &fs::read_to_string(&year_file)?,
+        &format!("mod d{day:02};"),
+        |line| line.starts_with("mod d"),
+        |line| {
+            line.strip_prefix("mod d")
+                .and_then(|l| l.strip_suffix(';')?.parse().ok())
+                .is_some_and(|d: u8| d > day)
+        },
+    );
+    fs::write(&year_file, mods)?;
+    fs::create_dir_all(module.parent().unwrap())?;
+    fs::write(&module, code)?;
+    println!("Created {}", module.display());
+
+    // Placeholders to fill in, so that tests and `run` find the puzzle; existing rows are left
+    // alone
+    conn.execute(
+        "INSERT INTO inputs (profile, year, day, input) VALUES (?, ?, ?, '')
+         ON CONFLICT DO NOTHING",
+        (profile, year, day),
+    )
+    .await?;
+    for part in 1..=2 {
+        conn.execute(
+            "INSERT INTO examples (year, day, part, label, input, output)
+             VALUES (?, ?, ?, '', '', '')
+             ON CONFLICT DO NOTHING",
+            (year, day, part),
+        )
+        .await?;
+    }
+    conn.execute(
+        "INSERT INTO puzzles (year, day, released_at) VALUES (?, ?, ?) ON CONFLICT DO NOTHING",
+        (year, day, release_time(year, day)),
+    )
+    .await?;
+    println!("Created empty input and example rows: profile={profile}, year={year}, day={day}");
+    Ok(())
+}
+
+/// What `watch` looks at: the sources of the solutions and of their macros, and what configures the
+/// build
+const WATCHED: &[&str] = &[
+    "src",
+    "macros/src",
+    "build.rs",
+    "Cargo.toml",
+    "macros/Cargo.toml",
+];
+
+/// The latest modification under the watched paths; directories count too, so that removals are
+/// seen.
+fn last_modified(root: &Path) -> Option<SystemTime> {
+    fn walk(path: &Path, latest: &mut Option<SystemTime>) {
+        let Ok(metadata) = fs::metadata(path) else {
+            return;
+        };
+        *latest = (*latest).max(metadata.modified().ok());
+        if metadata.is_dir() {
+            for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+                walk(&entry.path(), latest);
+            }
+        }
+    }
+    let mut latest = None;
+    for path in WATCHED {
+        walk(&root.join(path), &mut latest);
+    }
+    latest
+}
+
+/// Rebuilds and runs a puzzle, examples first, then waits for the next change to the sources, until
+/// interrupted.
+fn watch_day(
+    config: &DbConfig,
+    profile: &str,
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    // The same build profile and features as this binary, so that a release watch times release
+    // code, and a year left out stays out
+    let mut args = vec!["run", "--quiet", "--no-default-features"];
+    if !cfg!(debug_assertions) {
+        args.push("--release");
+    }
+    let features = env!("AOC_FEATURES");
+    if !features.is_empty() {
+        args.extend(["--features", features]);
+    }
+    let (year_arg, day_arg, part_arg) = (
+        year.to_string(),
+        day.to_string(),
+        part.map(|p| p.to_string()),
+    );
+    args.extend(["--", "run", &year_arg, &day_arg]);
+    args.extend(part_arg.as_deref());
+    // Every save would add a run to the history, mostly of unfinished code
+    args.extend(["--examples", "--no-record", "--profile", profile]);
+
+    loop {
+        let seen = last_modified(root);
+        // Clears the terminal, so that only the latest round shows
+        print!("\x1b[2J\x1b[H");
+        let puzzle = match part {
+            Some(part) => format!("{year} day {day} part {part}"),
+            None => format!("{year} day {day}"),
+        };
+        println!("Watching {puzzle}, Ctrl-C to stop\n");
+        let start = Instant::now();
+        let status = Command::new(&cargo)
+            .args(&args)
+            .current_dir(root)
+            .env(DB_ENV, config.to_string())
+            .status()?;
+        let outcome = if status.success() { "PASS" } else { "FAIL" };
+        println!("\n{outcome}: build and run took {:.1?}", start.elapsed());
+
+        while last_modified(root) == seen {
+            std::thread::sleep(Duration::from_millis(300));
+        }
+        // Editors often write a file in several steps
+        std::thread::sleep(Duration::from_millis(200));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `cargo bench -- --color=never`, cut down to a bench of each kind, with a day of two
+    /// profiles added for the units the real benches do not reach.
+    const DIVAN_OUTPUT: &str = "\
+bench                  fastest       │ slowest       │ median        │ mean          │ samples │ iters
+├─ y2025_d01_p1                      │               │               │               │         │
+│  ╰─ default          180 µs        │ 209.2 µs      │ 185 µs        │ 186.1 µs      │ 100     │ 100
+├─ y2025_d01_p2_naive                │               │               │               │         │
+│  ╰─ default          4.087 ms      │ 12.31 ms      │ 4.266 ms      │ 4.957 ms      │ 100     │ 100
+├─ y2025_d02_p1                      │               │               │               │         │
+│  ├─ alice            1.836 s       │ 2.012 s       │ 1.9 s         │ 1.91 s        │ 10      │ 10
+│  ╰─ default          886.3 ns      │ 1.2 µs        │ 900 ns        │ 950 ns        │ 100     │ 800
+├─ y2025_d08_p1_solve                │               │               │               │         │
+│  ╰─ default          186 ms        │ 304.1 ms      │ 259.5 ms      │ 255.6 ms      │ 100     │ 100
+├─ y2025_d08_p2                      │               │               │               │         │
+│  ╰─ default          40.88 ms      │ 51.65 ms      │ 42.1 ms       │ 42.51 ms      │ 100     │ 100
+╰─ y2025_d08_parse                   │               │               │               │         │
+   ╰─ default          151.8 µs      │ 566 µs        │ 158.3 µs      │ 164.3 µs      │ 100     │ 100
+";
+
+    fn parse_output(output: &str) -> Vec<BenchResult> {
+        let mut path = Vec::new();
+        output
+            .lines()
+            .filter_map(|line| parse_bench_line(line, &mut path))
+            .collect()
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() <= expected * 1e-9,
+            "{actual} != {expected}"
+        );
+    }
+
+    #[test]
+    fn bench_rows_are_read_under_their_bench() {
+        let results = parse_output(DIVAN_OUTPUT);
+        let rows: Vec<(&str, &str, u32)> = results
+            .iter()
+            .map(|result| (&result.name[..], &result.profile[..], result.samples))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ("y2025_d01_p1", "default", 100),
+                ("y2025_d01_p2_naive", "default", 100),
+                ("y2025_d02_p1", "alice", 10),
+                ("y2025_d02_p1", "default", 100),
+                ("y2025_d08_p1_solve", "default", 100),
+                ("y2025_d08_p2", "default", 100),
+                ("y2025_d08_parse", "default", 100),
+            ]
+        );
+    }
+
+    #[test]
+    fn bench_times_are_read_in_nanoseconds() {
+        let results = parse_output(DIVAN_OUTPUT);
+        let times = |i: usize| {
+            let result = &results[i];
+            (result.min_ns, result.median_ns, result.max_ns)
+        };
+
+        let (min, median, max) = times(0);
+        assert_close(min, 180e3);
+        assert_close(median, 185e3);
+        assert_close(max, 209.2e3);
+
+        let (min, median, max) = times(1);
+        assert_close(min, 4.087e6);
+        assert_close(median, 4.266e6);
+        assert_close(max, 12.31e6);
+
+        let (min, median, max) = times(2);
+        assert_close(min, 1.836e9);
+        assert_close(median, 1.9e9);
+        assert_close(max, 2.012e9);
+
+        let (min, median, max) = times(3);
+        assert_close(min, 886.3);
+        assert_close(median, 900.0);
+        assert_close(max, 1.2e3);
+    }
+
+    #[test]
+    fn header_and_bench_rows_have_no_result() {
+        // The header, then the line of each bench above those of its profiles
+        let skipped = [0, 1, 3, 5, 8, 10, 12];
+        let mut path = Vec::new();
+        for (i, line) in DIVAN_OUTPUT.lines().enumerate() {
+            let result = parse_bench_line(line, &mut path);
+            assert_eq!(result.is_none(), skipped.contains(&i), "{line}");
+        }
+        assert!(parse_bench_line("", &mut path).is_none());
+        assert!(parse_bench_line("     Running benches/bench.rs", &mut path).is_none());
+    }
+
+    #[test]
+    fn solution_benches_include_variants_but_not_stages() {
+        assert!(is_solution_bench("y2025_d01_p1"));
+        assert!(is_solution_bench("y2025_d01_p2_naive"));
+        assert!(is_solution_bench("y2025_d08_p2"));
+        assert!(!is_solution_bench("y2025_d08_p1_solve"));
+        assert!(!is_solution_bench("y2025_d08_parse"));
+        assert!(!is_solution_bench("y2025_d08"));
+        assert!(!is_solution_bench("fetch_input"));
     }
-    Ok(())
 }
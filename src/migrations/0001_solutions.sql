@@ -0,0 +1,8 @@
+CREATE TABLE solutions (
+    year INTEGER NOT NULL,
+    day INTEGER NOT NULL,
+    part INTEGER NOT NULL,
+    input TEXT NOT NULL,
+    output TEXT NOT NULL,
+    PRIMARY KEY (year, day, part)
+);
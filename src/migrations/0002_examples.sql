@@ -0,0 +1,9 @@
+CREATE TABLE examples (
+    year INTEGER NOT NULL,
+    day INTEGER NOT NULL,
+    part INTEGER NOT NULL,
+    label TEXT NOT NULL DEFAULT '',
+    input TEXT NOT NULL,
+    output TEXT NOT NULL,
+    PRIMARY KEY (year, day, part, label)
+);
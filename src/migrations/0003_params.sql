@@ -0,0 +1,2 @@
+ALTER TABLE solutions ADD COLUMN params TEXT NOT NULL DEFAULT '';
+ALTER TABLE examples ADD COLUMN params TEXT NOT NULL DEFAULT '';
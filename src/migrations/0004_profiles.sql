@@ -0,0 +1,15 @@
+-- The profile joins the primary key, which SQLite can only change by rebuilding the table
+ALTER TABLE solutions RENAME TO solutions_without_profile;
+CREATE TABLE solutions (
+    profile TEXT NOT NULL DEFAULT 'default',
+    year INTEGER NOT NULL,
+    day INTEGER NOT NULL,
+    part INTEGER NOT NULL,
+    input TEXT NOT NULL,
+    output TEXT NOT NULL,
+    params TEXT NOT NULL DEFAULT '',
+    PRIMARY KEY (profile, year, day, part)
+);
+INSERT INTO solutions (year, day, part, input, output, params)
+    SELECT year, day, part, input, output, params FROM solutions_without_profile;
+DROP TABLE solutions_without_profile;
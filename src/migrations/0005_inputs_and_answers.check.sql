@@ -0,0 +1,19 @@
+-- The parts of a day that cannot share one row of `inputs`: those stored with different inputs,
+-- or with different values for the same parameter
+WITH RECURSIVE pairs(profile, year, day, rest, pair) AS (
+    SELECT profile, year, day, params || ',', '' FROM solutions WHERE input != ''
+    UNION ALL
+    SELECT profile, year, day, substr(rest, instr(rest, ',') + 1),
+           trim(substr(rest, 1, instr(rest, ',') - 1))
+    FROM pairs WHERE rest != ''
+)
+SELECT printf('profile %s, %d day %d: the parts have different inputs', profile, year, day)
+    FROM solutions WHERE input != ''
+    GROUP BY profile, year, day HAVING COUNT(DISTINCT input) > 1
+UNION ALL
+SELECT printf('profile %s, %d day %d: the parts set parameter ''%s'' to %s', profile, year, day,
+              name, group_concat(DISTINCT value))
+    FROM (SELECT profile, year, day, trim(substr(pair, 1, instr(pair, '=') - 1)) AS name,
+                 trim(substr(pair, instr(pair, '=') + 1)) AS value
+          FROM pairs WHERE pair != '')
+    GROUP BY profile, year, day, name HAVING COUNT(DISTINCT value) > 1;
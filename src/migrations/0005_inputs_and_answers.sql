@@ -0,0 +1,35 @@
+CREATE TABLE inputs (
+    profile TEXT NOT NULL DEFAULT 'default',
+    year INTEGER NOT NULL,
+    day INTEGER NOT NULL,
+    input TEXT NOT NULL,
+    params TEXT NOT NULL DEFAULT '',
+    PRIMARY KEY (profile, year, day)
+);
+CREATE TABLE answers (
+    profile TEXT NOT NULL DEFAULT 'default',
+    year INTEGER NOT NULL,
+    day INTEGER NOT NULL,
+    part INTEGER NOT NULL,
+    output TEXT NOT NULL,
+    PRIMARY KEY (profile, year, day, part)
+);
+-- The parameters of the parts are merged, each part ignoring those of the other; parts with
+-- different inputs or parameter values were turned away by the check beforehand
+WITH RECURSIVE pairs(profile, year, day, rest, pair) AS (
+    SELECT profile, year, day, params || ',', '' FROM solutions WHERE input != ''
+    UNION ALL
+    SELECT profile, year, day, substr(rest, instr(rest, ',') + 1),
+           trim(substr(rest, 1, instr(rest, ',') - 1))
+    FROM pairs WHERE rest != ''
+)
+INSERT INTO inputs (profile, year, day, input, params)
+    SELECT s.profile, s.year, s.day, s.input,
+           COALESCE((SELECT group_concat(DISTINCT p.pair) FROM pairs p
+                     WHERE p.profile = s.profile AND p.year = s.year AND p.day = s.day
+                       AND p.pair != ''), '')
+    FROM solutions s WHERE s.input != ''
+    GROUP BY s.profile, s.year, s.day;
+INSERT INTO answers (profile, year, day, part, output)
+    SELECT profile, year, day, part, output FROM solutions WHERE output != '';
+DROP TABLE solutions;
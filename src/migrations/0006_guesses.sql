@@ -0,0 +1,11 @@
+CREATE TABLE guesses (
+    profile TEXT NOT NULL,
+    year INTEGER NOT NULL,
+    day INTEGER NOT NULL,
+    part INTEGER NOT NULL,
+    answer TEXT NOT NULL,
+    -- correct, wrong, or too_high/too_low when the site says so
+    verdict TEXT NOT NULL CHECK (verdict IN ('correct', 'wrong', 'too_high', 'too_low')),
+    guessed_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (profile, year, day, part, answer)
+);
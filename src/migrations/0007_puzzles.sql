@@ -0,0 +1,24 @@
+CREATE TABLE puzzles (
+    year INTEGER NOT NULL,
+    day INTEGER NOT NULL,
+    title TEXT NOT NULL DEFAULT '',
+    -- UTC, as `YYYY-MM-DD HH:MM:SS` like CURRENT_TIMESTAMP
+    released_at TEXT NOT NULL,
+    notes TEXT NOT NULL DEFAULT '',
+    PRIMARY KEY (year, day)
+);
+CREATE TABLE puzzle_tags (
+    year INTEGER NOT NULL,
+    day INTEGER NOT NULL,
+    tag TEXT NOT NULL,
+    PRIMARY KEY (year, day, tag)
+);
+CREATE INDEX puzzle_tags_by_tag ON puzzle_tags (tag);
+CREATE TABLE completions (
+    profile TEXT NOT NULL,
+    year INTEGER NOT NULL,
+    day INTEGER NOT NULL,
+    part INTEGER NOT NULL,
+    completed_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (profile, year, day, part)
+);
@@ -0,0 +1,17 @@
+CREATE TABLE runs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    profile TEXT NOT NULL,
+    year INTEGER NOT NULL,
+    day INTEGER NOT NULL,
+    part INTEGER NOT NULL,
+    variant TEXT NOT NULL DEFAULT '',
+    -- `git rev-parse HEAD`, suffixed with `-dirty` for uncommitted changes; '' outside a checkout
+    git_commit TEXT NOT NULL DEFAULT '',
+    -- NULL when the solution returned an error
+    answer TEXT,
+    wall_time_ns INTEGER NOT NULL,
+    -- ok, fail, error, or unchecked when there is no stored output
+    status TEXT NOT NULL,
+    ran_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX runs_by_puzzle ON runs (profile, year, day, part);
@@ -0,0 +1,14 @@
+CREATE TABLE bench_results (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    -- `git rev-parse HEAD`, suffixed with `-dirty` for uncommitted changes; '' outside a checkout
+    git_commit TEXT NOT NULL DEFAULT '',
+    -- Name of the divan bench, e.g. y2025_d08_p1 or y2025_d08_parse
+    name TEXT NOT NULL,
+    profile TEXT NOT NULL,
+    median_ns REAL NOT NULL,
+    min_ns REAL NOT NULL,
+    max_ns REAL NOT NULL,
+    samples INTEGER NOT NULL,
+    recorded_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX bench_results_by_commit ON bench_results (git_commit, name, profile);
@@ -0,0 +1,11 @@
+-- Every request made to the site, to throttle them across runs
+CREATE TABLE requests (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    profile TEXT NOT NULL,
+    method TEXT NOT NULL,
+    -- e.g. /2025/day/8/input
+    path TEXT NOT NULL,
+    -- HTTP status, NULL when no response came back
+    status INTEGER,
+    requested_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
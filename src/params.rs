@@ -0,0 +1,95 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Solution, SolveError};
+
+/// Named integer parameters of a puzzle, for values such as a grid size or a
+/// number of steps that differ between the examples and the real input.
+///
+/// Written as `name=value` pairs separated by commas, e.g. `connections=10`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params(Vec<(String, i64)>);
+
+impl Params {
+    /// The value of a declared parameter.
+    ///
+    /// Panics if `name` is not declared by the solution.
+    pub fn get(&self, name: &str) -> i64 {
+        self.0
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| *v)
+            .unwrap_or_else(|| panic!("undeclared parameter '{name}'"))
+    }
+
+    pub fn set(&mut self, name: &str, value: i64) {
+        match self.0.iter_mut().find(|(n, _)| n == name) {
+            Some((_, v)) => *v = value,
+            None => self.0.push((name.to_string(), value)),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, i64)> {
+        self.0.iter().map(|(n, v)| (n.as_str(), *v))
+    }
+
+    /// The parameters declared by `solution` with the given `overrides` applied.
+    ///
+    /// The parts of a day share the stored parameters, so overrides that only another
+    /// solution of the day declares are skipped, and any other undeclared one is rejected.
+    pub fn resolve(solution: &dyn Solution, overrides: &Params) -> Result<Params, SolveError> {
+        let defaults = solution.params();
+        let mut params = Params(defaults.iter().map(|(n, v)| (n.to_string(), *v)).collect());
+        for (name, value) in &overrides.0 {
+            if defaults.iter().any(|(n, _)| n == name) {
+                params.set(name, *value);
+            } else if !declared_by_day(solution.year(), solution.day(), name) {
+                return Err(SolveError::new(format!("unknown parameter '{name}'")));
+            }
+        }
+        Ok(params)
+    }
+}
+
+/// Whether any solution of the day declares the parameter `name`.
+fn declared_by_day(year: u16, day: u8, name: &str) -> bool {
+    inventory::iter::<&dyn Solution>
+        .into_iter()
+        .filter(|s| s.year() == year && s.day() == day)
+        .any(|s| s.params().iter().any(|(n, _)| *n == name))
+}
+
+impl FromStr for Params {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::default();
+        for pair in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (name, value) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("expected 'name=value', got '{pair}'"))?;
+            let value = value
+                .trim()
+                .parse()
+                .map_err(|e| format!("invalid value for parameter '{}': {e}", name.trim()))?;
+            params.set(name.trim(), value);
+        }
+        Ok(params)
+    }
+}
+
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, value)) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{name}={value}")?;
+        }
+        Ok(())
+    }
+}
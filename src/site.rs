@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+use crate::config::ConfigFile;
+
+/// Environment variable holding the session cookie of the `default` profile, overriding `aoc.conf`;
+/// another profile reads its own, e.g. `AOC_SESSION_ALICE` for `alice`
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Environment variable naming the site to talk to, e.g. a local mock server
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Shortest time between two requests to the site, which asks to be throttled
+pub const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The Advent of Code site, or a server answering like it, as one account sees it.
+pub struct Site {
+    base_url: String,
+    session: String,
+    client: reqwest::Client,
+}
+
+impl Site {
+    /// Picks the site from `AOC_BASE_URL`, `base_url` in `aoc.conf` or the real one, and the
+    /// session cookie of `profile` from `AOC_SESSION` (`AOC_SESSION_<PROFILE>` for another
+    /// profile), or `session` (`session.<profile>`) in `aoc.conf`.
+    pub fn resolve(profile: &str) -> Result<Self, String> {
+        let mut file = ConfigFile::read()?;
+        let base_url = std::env::var(BASE_URL_ENV)
+            .ok()
+            .or(file.base_url)
+            .unwrap_or(DEFAULT_BASE_URL.to_string());
+        let (env, key) = match profile {
+            "default" => (SESSION_ENV.to_string(), "session".to_string()),
+            _ => (session_env(profile), format!("session.{profile}")),
+        };
+        let session = std::env::var(&env)
+            .ok()
+            .or_else(|| file.sessions.remove(profile))
+            .ok_or_else(|| {
+                format!(
+                    "No session cookie for profile {profile}, \
+                     set {env} or `{key}` in aoc.conf"
+                )
+            })?;
+
+        let client = reqwest::Client::builder()
+            .user_agent(concat!(
+                env!("CARGO_PKG_NAME"),
+                "/",
+                env!("CARGO_PKG_VERSION")
+            ))
+            .timeout(Duration::from_secs(30))
+            .build()
+            .map_err(|e| e.to_string())?;
+        Ok(Site {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            client,
+        })
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    /// Requests `path`, returning the status code and body whatever the status.
+    pub async fn get(&self, path: &str) -> Result<(u16, String), reqwest::Error> {
+        let request = self.client.get(self.url(path));
+        self.send(request).await
+    }
+
+    /// Posts a form to `path`, returning the status code and body whatever the status.
+    pub async fn post(
+        &self,
+        path: &str,
+        form: &[(&str, &str)],
+    ) -> Result<(u16, String), reqwest::Error> {
+        let request = self.client.post(self.url(path)).form(form);
+        self.send(request).await
+    }
+
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<(u16, String), reqwest::Error> {
+        let response = request
+            .header("Cookie", format!("session={}", self.session))
+            .send()
+            .await?;
+        let status = response.status().as_u16();
+        Ok((status, response.text().await?))
+    }
+}
+
+/// The variable holding the session cookie of a profile other than `default`, e.g.
+/// `AOC_SESSION_ALICE`, so that the one in `AOC_SESSION` is never sent for another account.
+fn session_env(profile: &str) -> String {
+    let suffix: String = profile
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' => c.to_ascii_uppercase(),
+            _ => '_',
+        })
+        .collect();
+    format!("{SESSION_ENV}_{suffix}")
+}
@@ -0,0 +1,105 @@
+use crate::solution;
+
+#[solution(2025, 1, 1)]
+mod s {
+    use crate::{SolveError, parse};
+
+    fn solve(s: &str) -> Result<u32, SolveError> {
+        let mut num_zeros: u32 = 0;
+        let mut current: i32 = 50;
+
+        for line in s.lines() {
+            let (direction, delta) = line
+                .split_at_checked(1)
+                .ok_or_else(|| SolveError::at(s, line, "empty line"))?;
+            let delta = parse::<i32>(s, delta)?;
+
+            match direction {
+                "R" => current += delta,
+                "L" => current -= delta,
+                _ => return Err(SolveError::at(s, line, "expected 'L' or 'R'")),
+            }
+
+            current = current.rem_euclid(100);
+
+            num_zeros += (current == 0) as u32;
+        }
+
+        Ok(num_zeros)
+    }
+}
+
+#[solution(2025, 1, 2)]
+mod s {
+    use crate::{SolveError, parse};
+
+    fn solve(s: &str) -> Result<i32, SolveError> {
+        let mut num_zeros: i32 = 0;
+        let mut current: i32 = 50;
+
+        for line in s.lines() {
+            let (direction, delta) = line
+                .split_at_checked(1)
+                .ok_or_else(|| SolveError::at(s, line, "empty line"))?;
+            let delta = parse::<i32>(s, delta)?;
+
+            match direction {
+                "R" => {
+                    num_zeros += (current + delta).div_euclid(100);
+                    current = (current + delta).rem_euclid(100);
+                }
+                "L" => {
+                    num_zeros += (delta + 100 - current).div_euclid(100) - (current == 0) as i32;
+                    current = (current - delta).rem_euclid(100);
+                }
+                _ => return Err(SolveError::at(s, line, "expected 'L' or 'R'")),
+            };
+        }
+
+        Ok(num_zeros)
+    }
+}
+
+#[solution(2025, 1, 2, variant = "naive", reference)]
+mod s {
+    use crate::{Rng, SolveError, parse};
+
+    fn generate(rng: &mut Rng) -> String {
+        let mut input = String::new();
+        for _ in 0..rng.range(1, 200) {
+            let direction = if rng.chance(1, 2) { 'L' } else { 'R' };
+            // Favour exact multiples of 100, where off-by-one errors hide
+            let delta = match rng.range(0, 3) {
+                0 => rng.range(1, 9) * 100,
+                _ => rng.range(1, 999),
+            };
+            input.push_str(&format!("{direction}{delta}\n"));
+        }
+        input
+    }
+
+    fn solve(s: &str) -> Result<i32, SolveError> {
+        let mut num_zeros: i32 = 0;
+        let mut current: i32 = 50;
+
+        for line in s.lines() {
+            let (direction, delta) = line
+                .split_at_checked(1)
+                .ok_or_else(|| SolveError::at(s, line, "empty line"))?;
+            let delta = parse::<i32>(s, delta)?;
+
+            let step = match direction {
+                "R" => 1,
+                "L" => -1,
+                _ => return Err(SolveError::at(s, line, "expected 'L' or 'R'")),
+            };
+
+            for _ in 0..delta {
+                current = (current + step).rem_euclid(100);
+                num_zeros += (current == 0) as i32;
+            }
+        }
+
+        Ok(num_zeros)
+    }
+}
@@ -0,0 +1,108 @@
+use crate::solution;
+
+#[solution(2025, 2, 1)]
+mod s {
+    use crate::{SolveError, parse};
+    use ahash::{HashMap, HashMapExt};
+
+    fn is_invalid(n: u64) -> bool {
+        let num_digits = n.ilog10() + 1;
+
+        if !num_digits.is_multiple_of(2) {
+            return false;
+        }
+
+        let cut = 10_u64.pow(num_digits / 2);
+
+        n % cut == n / cut
+    }
+
+    fn solve(input: &str) -> Result<u64, SolveError> {
+        let mut total: u64 = 0;
+        let mut cache: HashMap<u64, bool> = HashMap::new();
+
+        // Trimmed rather than stripped of newlines, so that errors point into the input
+        for pair in input.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (start, end) = pair
+                .split_once('-')
+                .ok_or_else(|| SolveError::at(input, pair, "expected a range 'start-end'"))?;
+
+            let start: u64 = parse(input, start)?;
+            let end: u64 = parse(input, end)?;
+
+            for n in start..=end {
+                if *cache.entry(n).or_insert_with(|| is_invalid(n)) {
+                    total += n;
+                }
+            }
+        }
+
+        Ok(total)
+    }
+}
+
+#[solution(2025, 2, 2)]
+mod s {
+    use crate::{SolveError, parse};
+    use ahash::{HashMap, HashMapExt};
+
+    // u64::MAX.ilog10() == 19
+    const PRIMES: [u64; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+
+    fn is_invalid_p2(n: u64) -> bool {
+        let num_digits = (n.ilog10() + 1) as u64;
+
+        for s in PRIMES {
+            if is_invalid_s(n, s, num_digits) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn is_invalid_s(n: u64, s: u64, num_digits: u64) -> bool {
+        if !num_digits.is_multiple_of(s) {
+            return false;
+        }
+
+        let cut = 10_u64.pow((num_digits / s) as u32);
+
+        let lower = n % cut;
+
+        let mut n = n / cut;
+
+        while n != 0 {
+            if (n % cut) != lower {
+                return false;
+            }
+
+            n /= cut;
+        }
+
+        true
+    }
+
+    fn solve(input: &str) -> Result<u64, SolveError> {
+        let mut total: u64 = 0;
+        let mut cache: HashMap<u64, bool> = HashMap::new();
+
+        // Trimmed rather than stripped of newlines, so that errors point into the input
+        for pair in input.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (start, end) = pair
+                .split_once('-')
+                .ok_or_else(|| SolveError::at(input, pair, "expected a range 'start-end'"))?;
+
+            let start: u64 = parse(input, start)?;
+            let end: u64 = parse(input, end)?;
+
+            for n in start..=end {
+                if *cache.entry(n).or_insert_with(|| is_invalid_p2(n)) {
+                    total += n;
+                }
+            }
+        }
+
+        Ok(total)
+    }
+}
@@ -0,0 +1,57 @@
+use crate::solution;
+
+#[solution(2025, 3, 1)]
+mod s {
+    fn solve(s: &str) -> u32 {
+        let mut result: u32 = 0;
+
+        for line in s.lines() {
+            let mut m = 48;
+            let mut j = 48;
+
+            for (i, b) in line.bytes().enumerate() {
+                if i == line.len() - 1 {
+                    continue;
+                }
+                if b > m {
+                    m = b;
+                    j = i;
+                }
+            }
+
+            let n = line.bytes().skip(j + 1).max().unwrap();
+            result += (m as u32 - 48) * 10 + n as u32 - 48;
+        }
+
+        result
+    }
+}
+
+#[solution(2025, 3, 2)]
+mod s {
+    fn solve(s: &str) -> u64 {
+        let mut result: u64 = 0;
+
+        for line in s.lines() {
+            let bytes = line.as_bytes();
+
+            let mut j = 0;
+            let mut current = 0;
+
+            for remaining in (0..12).rev() {
+                let (i, n) = bytes[j..(line.len() - remaining)].iter().enumerate().fold(
+                    (0, 0),
+                    |(i, n), (k, v)| if *v > n { (k, *v) } else { (i, n) },
+                );
+
+                j += i + 1;
+                current *= 10;
+                current += n as u64 - 48;
+            }
+
+            result += current;
+        }
+
+        result
+    }
+}
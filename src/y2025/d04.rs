@@ -0,0 +1,67 @@
+use crate::day;
+
+#[day(2025, 4)]
+mod s {
+    use ahash::{HashSet, HashSetExt as _};
+
+    type Grid = HashSet<(usize, usize)>;
+
+    fn num_neighbors(p: &(usize, usize), grid: &Grid) -> usize {
+        let mut n = 0;
+
+        n += grid.contains(&(p.0 - 1, p.1 - 1)) as usize;
+        n += grid.contains(&(p.0, p.1 - 1)) as usize;
+        n += grid.contains(&(p.0 + 1, p.1 - 1)) as usize;
+        n += grid.contains(&(p.0 - 1, p.1)) as usize;
+        n += grid.contains(&(p.0 + 1, p.1)) as usize;
+        n += grid.contains(&(p.0 - 1, p.1 + 1)) as usize;
+        n += grid.contains(&(p.0, p.1 + 1)) as usize;
+        n += grid.contains(&(p.0 + 1, p.1 + 1)) as usize;
+
+        n
+    }
+
+    fn try_remove(p: &(usize, usize), grid: &mut Grid) {
+        if num_neighbors(p, grid) < 4 {
+            grid.remove(p);
+        }
+    }
+
+    fn parse(s: &str) -> Grid {
+        let mut grid = HashSet::new();
+
+        for (y, l) in s.lines().enumerate() {
+            for (x, _) in l.chars().enumerate().filter(|(_, c)| *c == '@') {
+                grid.insert((x + 1, y + 1));
+            }
+        }
+
+        grid
+    }
+
+    fn part1(grid: &Grid) -> usize {
+        grid.iter().filter(|p| num_neighbors(p, grid) < 4).count()
+    }
+
+    fn part2(grid: &Grid) -> usize {
+        let mut grid = grid.clone();
+
+        let n = grid.len();
+
+        let mut has_changed = true;
+
+        while has_changed {
+            let num_before = grid.len();
+
+            let points: Vec<_> = grid.iter().copied().collect();
+
+            for p in points {
+                try_remove(&p, &mut grid);
+            }
+
+            has_changed = num_before - grid.len() > 0;
+        }
+
+        n - grid.len()
+    }
+}
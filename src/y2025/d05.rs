@@ -0,0 +1,90 @@
+use crate::solution;
+
+#[solution(2025, 5, 1)]
+mod s {
+    use crate::{SolveError, parse};
+    use ahash::{HashSet, HashSetExt};
+
+    type Range = (u64, u64);
+
+    fn parse_range(s: &str, line: &str) -> Result<Range, SolveError> {
+        let (i, j) = line
+            .split_once('-')
+            .ok_or_else(|| SolveError::at(s, line, "expected a range 'start-end'"))?;
+        Ok((parse(s, i)?, parse(s, j)?))
+    }
+
+    fn solve(s: &str) -> Result<usize, SolveError> {
+        let (ranges, ids) = s
+            .split_once("\n\n")
+            .ok_or("expected ranges and ids separated by a blank line")?;
+
+        let ids: Vec<u64> = ids
+            .lines()
+            .map(|id| parse(s, id))
+            .collect::<Result<_, _>>()?;
+        let ranges: Vec<Range> = ranges
+            .lines()
+            .map(|line| parse_range(s, line))
+            .collect::<Result<_, _>>()?;
+
+        let mut chosen = HashSet::with_capacity(ids.len());
+
+        for id in ids {
+            for range in &ranges {
+                if range.0 <= id && id <= range.1 {
+                    chosen.insert(id);
+                }
+            }
+        }
+
+        Ok(chosen.len())
+    }
+}
+
+#[solution(2025, 5, 2)]
+mod s {
+    use crate::{SolveError, parse};
+
+    type Range = (u64, u64);
+
+    fn parse_range(s: &str, line: &str) -> Result<Range, SolveError> {
+        let (i, j) = line
+            .split_once('-')
+            .ok_or_else(|| SolveError::at(s, line, "expected a range 'start-end'"))?;
+        Ok((parse(s, i)?, parse(s, j)?))
+    }
+
+    fn solve(s: &str) -> Result<u64, SolveError> {
+        let (ranges, _) = s
+            .split_once("\n\n")
+            .ok_or("expected ranges and ids separated by a blank line")?;
+
+        let mut ranges: Vec<Range> = ranges
+            .lines()
+            .map(|line| parse_range(s, line))
+            .collect::<Result<_, _>>()?;
+
+        ranges.sort_by_key(|a| a.0);
+
+        let mut ranges = ranges.into_iter();
+
+        let mut res = 0;
+
+        let mut current = ranges.next().ok_or("expected at least one range")?;
+
+        for range in ranges {
+            if range.0 <= current.1 {
+                current.1 = range.1.max(current.1)
+            } else {
+                res += current.1 - current.0 + 1;
+                current.0 = range.0;
+                current.1 = range.1;
+            }
+        }
+
+        res += current.1 - current.0 + 1;
+
+        Ok(res)
+    }
+}
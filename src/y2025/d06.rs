@@ -0,0 +1,167 @@
+use crate::solution;
+
+#[solution(2025, 6, 1, params(rows = 4))]
+mod s {
+    use crate::{Params, SolveError, parse};
+    use ahash::{HashMap, HashMapExt};
+
+    fn solve_p1(s: &str, rows: usize) -> Result<u128, SolveError> {
+        let ops = s
+            .lines()
+            .last()
+            .ok_or_else(|| SolveError::new("empty input"))?
+            .split_whitespace()
+            .enumerate()
+            .collect::<HashMap<usize, &str>>();
+
+        let mut accs = HashMap::<usize, u128>::with_capacity(ops.len());
+
+        for line in s.lines().take(rows) {
+            for (i, token) in line.split_whitespace().enumerate() {
+                let n = parse(s, token)?;
+                match accs.get_mut(&i) {
+                    Some(acc) => {
+                        *acc = match ops.get(&i) {
+                            Some(&"*") => *acc * n,
+                            Some(&"+") => *acc + n,
+                            Some(op) => {
+                                let message = format!("expected '*' or '+', got '{op}'");
+                                return Err(SolveError::at(s, op, message));
+                            }
+                            None => {
+                                return Err(SolveError::at(
+                                    s,
+                                    token,
+                                    "no operator below this number",
+                                ));
+                            }
+                        };
+                    }
+                    None => {
+                        accs.insert(i, n);
+                    }
+                }
+            }
+        }
+
+        Ok(accs.values().sum())
+    }
+
+    fn solve(s: &str, params: &Params) -> Result<u128, SolveError> {
+        let rows = params.get("rows");
+        let rows = usize::try_from(rows)
+            .map_err(|_| SolveError::new(format!("rows must not be negative, got {rows}")))?;
+        solve_p1(s, rows)
+    }
+}
+
+#[solution(2025, 6, 2, params(rows = 4))]
+mod s {
+    use crate::{Params, SolveError};
+    use ahash::{HashMap, HashMapExt};
+
+    fn solve_p2(s: &str, rows: usize) -> Result<u128, SolveError> {
+        let ops = s
+            .lines()
+            .last()
+            .ok_or_else(|| SolveError::new("empty input"))?
+            .split_whitespace()
+            .enumerate()
+            .collect::<HashMap<usize, &str>>();
+        if ops.is_empty() {
+            return Err(SolveError::new("no operators on the last line"));
+        }
+
+        let mut data = HashMap::<usize, Vec<char>>::with_capacity(ops.len());
+
+        for (j, line) in s.lines().take(rows).enumerate() {
+            for (i, char) in line.chars().enumerate() {
+                data.entry(i)
+                    .and_modify(|v| v[j] = char)
+                    .or_insert_with(|| {
+                        let mut new = vec![' '; rows];
+                        new[0] = char;
+                        new
+                    });
+            }
+        }
+
+        let mut separators = data
+            .iter()
+            .filter(|(_, v)| v.iter().all(|c| c.is_whitespace()))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        separators.sort();
+
+        let mut cols = HashMap::<usize, Vec<Vec<Option<u8>>>>::with_capacity(ops.len());
+
+        for (j, line) in s.lines().take(rows).enumerate() {
+            for (i, char) in line.chars().enumerate() {
+                if separators.contains(&&i) {
+                    continue;
+                }
+                let k = separators
+                    .iter()
+                    .position(|&s| &i <= s)
+                    .unwrap_or(ops.len() - 1);
+                cols.entry(k)
+                    .and_modify(|rows| {
+                        rows[j].push(char.to_digit(10).map(|d| d as u8));
+                    })
+                    .or_insert_with(|| {
+                        let mut empty = vec![Vec::new(); rows];
+                        empty[j].push(char.to_digit(10).map(|d| d as u8));
+                        empty
+                    });
+            }
+        }
+
+        let mut accs = HashMap::<usize, u128>::with_capacity(ops.len());
+
+        for (i, col) in cols {
+            let col_size = col.iter().map(|v| v.len()).max().unwrap_or(0);
+
+            for j in 0..col_size {
+                let mut acc = 0;
+
+                for row in &col {
+                    if let Some(Some(d)) = row.get(j) {
+                        acc *= 10;
+                        acc += *d as u32;
+                    }
+                }
+
+                let acc = acc as u128;
+                match accs.get_mut(&i) {
+                    Some(v) => {
+                        *v = match ops.get(&i) {
+                            Some(&"*") => *v * acc,
+                            Some(&"+") => *v + acc,
+                            Some(op) => {
+                                let message = format!("expected '*' or '+', got '{op}'");
+                                return Err(SolveError::at(s, op, message));
+                            }
+                            None => {
+                                let message = format!("no operator below column {}", i + 1);
+                                return Err(SolveError::new(message));
+                            }
+                        };
+                    }
+                    None => {
+                        accs.insert(i, acc);
+                    }
+                }
+            }
+        }
+
+        Ok(accs.values().sum())
+    }
+
+    fn solve(s: &str, params: &Params) -> Result<u128, SolveError> {
+        let rows = params.get("rows");
+        let rows = usize::try_from(rows)
+            .map_err(|_| SolveError::new(format!("rows must not be negative, got {rows}")))?;
+        solve_p2(s, rows)
+    }
+}
@@ -0,0 +1,153 @@
+use crate::day;
+
+#[day(2025, 8, params(connections = 1000))]
+mod s {
+    use crate::{Params, SolveError};
+    use ahash::{HashSet, HashSetExt};
+    use std::collections::BinaryHeap;
+
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, Default)]
+    struct Coords(i64, i64, i64);
+
+    #[derive(Hash, PartialEq, Eq, Clone)]
+    struct Pair(Coords, Coords);
+
+    impl Ord for Pair {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            distance(&other.0, &other.1).cmp(&distance(&self.0, &self.1))
+        }
+    }
+
+    impl PartialOrd for Pair {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    fn distance(rhs: &Coords, lhs: &Coords) -> i64 {
+        (rhs.0 - lhs.0).pow(2) + (rhs.1 - lhs.1).pow(2) + (rhs.2 - lhs.2).pow(2)
+    }
+
+    fn find(parent: &mut Vec<usize>, x: usize) -> usize {
+        if parent[x] != x {
+            parent[x] = find(parent, parent[x]);
+        }
+        parent[x]
+    }
+
+    fn union(parent: &mut Vec<usize>, rank: &mut [u8], a: usize, b: usize) -> bool {
+        let ra = find(parent, a);
+        let rb = find(parent, b);
+        if ra == rb {
+            return false;
+        }
+        if rank[ra] < rank[rb] {
+            parent[ra] = rb;
+        } else if rank[ra] > rank[rb] {
+            parent[rb] = ra;
+        } else {
+            parent[rb] = ra;
+            rank[ra] += 1;
+        }
+        true
+    }
+
+    fn parse(s: &str) -> Result<Vec<Coords>, SolveError> {
+        let mut points: Vec<Coords> = vec![];
+
+        for line in s.lines() {
+            let mut numbers = line.split(",");
+            let mut next = || {
+                let n = numbers
+                    .next()
+                    .ok_or_else(|| SolveError::at(s, line, "expected coordinates 'x,y,z'"))?;
+                crate::parse(s, n)
+            };
+
+            let x = next()?;
+            let y = next()?;
+            let z = next()?;
+
+            points.push(Coords(x, y, z));
+        }
+
+        Ok(points)
+    }
+
+    fn part1(points: &[Coords], params: &Params) -> Result<usize, SolveError> {
+        let mut distances = BinaryHeap::<Pair>::with_capacity(points.len() * points.len());
+
+        for i in 0..points.len() {
+            for j in 0..i {
+                distances.push(Pair(points[i], points[j]));
+            }
+        }
+
+        let mut edges = HashSet::with_capacity(points.len() * points.len());
+
+        for _ in 0..params.get("connections") {
+            let p = distances
+                .pop()
+                .ok_or("fewer pairs of junction boxes than connections")?;
+            edges.insert(p);
+        }
+
+        let mut sizes: Vec<usize> = vec![];
+
+        let mut remaining = points.to_vec();
+
+        while let Some(point) = remaining.pop() {
+            let mut visited: HashSet<Coords> = HashSet::new();
+            let mut queue: Vec<Coords> = vec![point];
+
+            while let Some(point) = queue.pop() {
+                if !visited.contains(&point) {
+                    let e = edges.iter().filter(|p| p.0 == point || p.1 == point);
+
+                    for p in e {
+                        queue.push(p.0);
+                        queue.push(p.1);
+                    }
+                }
+
+                visited.insert(point);
+            }
+
+            sizes.push(visited.len());
+
+            remaining.retain(|c| !visited.contains(c))
+        }
+
+        sizes.sort();
+
+        Ok(sizes.iter().rev().take(3).product())
+    }
+
+    fn part2(points: &[Coords]) -> Result<usize, SolveError> {
+        let n = points.len();
+        let mut edges: Vec<(i64, usize, usize)> = Vec::with_capacity(n * n.saturating_sub(1) / 2);
+
+        for i in 0..n {
+            for j in 0..i {
+                edges.push((distance(&points[i], &points[j]), i, j));
+            }
+        }
+
+        edges.sort_unstable();
+
+        let mut parent: Vec<usize> = (0..n).collect();
+        let mut rank = vec![0u8; n];
+        let mut components = n;
+
+        for &(_, i, j) in &edges {
+            if union(&mut parent, &mut rank, i, j) {
+                components -= 1;
+                if components == 1 {
+                    return Ok((points[i].0 * points[j].0) as usize);
+                }
+            }
+        }
+
+        Err("junction boxes never form a single circuit".into())
+    }
+}
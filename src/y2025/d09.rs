@@ -0,0 +1,99 @@
+use crate::solution;
+
+#[solution(2025, 9, 1)]
+mod s {
+    use crate::{SolveError, parse};
+
+    fn compute_area(a: &(usize, usize), b: &(usize, usize)) -> usize {
+        let p = (a.0.min(b.0), a.1.min(b.1));
+        let q = (a.0.max(b.0), a.1.max(b.1));
+
+        (q.0 - p.0 + 1) * (q.1 - p.1 + 1)
+    }
+
+    fn solve(s: &str) -> Result<usize, SolveError> {
+        let mut points = vec![];
+
+        for line in s.lines() {
+            let coords = line
+                .split_once(',')
+                .ok_or_else(|| SolveError::at(s, line, "expected coordinates 'x,y'"))?;
+            let x: usize = parse(s, coords.0)?;
+            let y: usize = parse(s, coords.1)?;
+
+            points.push((x, y));
+        }
+
+        let mut res = 0;
+
+        for i in 0..points.len() {
+            for j in 0..i {
+                let a = compute_area(&points[i], &points[j]);
+                if a > res {
+                    res = a;
+                }
+            }
+        }
+
+        Ok(res)
+    }
+}
+
+#[solution(2025, 9, 2)]
+mod s {
+    use crate::{SolveError, parse};
+
+    fn normalize(p: &(u64, u64), q: &(u64, u64)) -> ((u64, u64), (u64, u64)) {
+        ((p.0.min(q.0), p.1.min(q.1)), (p.0.max(q.0), p.1.max(q.1)))
+    }
+
+    fn solve(s: &str) -> Result<u64, SolveError> {
+        let mut points = vec![];
+
+        for line in s.lines() {
+            let coords = line
+                .split_once(',')
+                .ok_or_else(|| SolveError::at(s, line, "expected coordinates 'x,y'"))?;
+
+            let x: u64 = parse(s, coords.0)?;
+            let y: u64 = parse(s, coords.1)?;
+
+            points.push((x, y));
+        }
+
+        let mut rectangles = Vec::with_capacity(points.len() * points.len());
+
+        for i in 0..points.len() {
+            for j in 0..i {
+                let (a, b) = normalize(&points[i], &points[j]);
+                let area = (b.0 - a.0 + 1) * (b.1 - a.1 + 1);
+
+                rectangles.push((a, b, area));
+            }
+        }
+
+        rectangles.sort_by_key(|(_, _, a)| *a);
+
+        let n = points.len();
+        points.push(*points.first().ok_or("expected at least one point")?);
+
+        for (a, b, area) in rectangles.iter().rev() {
+            let mut intersects = false;
+
+            for i in 0..n {
+                let (p, q) = normalize(&points[i], &points[i + 1]);
+
+                if !(b.0 <= p.0 || a.0 >= q.0 || b.1 <= p.1 || a.1 >= p.1) {
+                    intersects = true;
+                    break;
+                }
+            }
+
+            if !intersects {
+                return Ok(*area);
+            }
+        }
+
+        Err("no rectangle fits inside the polygon".into())
+    }
+}
@@ -0,0 +1,158 @@
+use crate::solution;
+
+#[solution(2025, 10, 1)]
+mod s {
+    use std::collections::{HashSet, VecDeque};
+
+    fn solve(s: &str) -> usize {
+        let mut res = 0;
+
+        for line in s.lines() {
+            let mut sections = line.split_whitespace();
+
+            let end_state = sections
+                .next()
+                .unwrap()
+                .trim_matches(|c| c == '[' || c == ']')
+                .chars()
+                .map(|c| c == '#')
+                .enumerate()
+                .fold(0u64, |acc, (i, b)| acc | (b as u64) << i);
+
+            let actions = sections
+                .take_while(|&s| s.starts_with('('))
+                .map(|section| {
+                    section
+                        .trim_matches(|c| c == '(' || c == ')')
+                        .split(',')
+                        .map(|s| s.parse::<usize>().unwrap())
+                        .fold(0u64, |acc, b| acc | (1u64 << b))
+                })
+                .collect::<Vec<_>>();
+
+            //                  (state, depth)
+            let initial_state = (0, 0);
+
+            let mut visited: HashSet<u64> = HashSet::new();
+            let mut queue: VecDeque<_> = vec![initial_state].into();
+
+            while let Some(state) = queue.pop_back() {
+                if visited.contains(&state.0) {
+                    continue;
+                }
+
+                if state.0 == end_state {
+                    res += state.1;
+                    break;
+                }
+
+                let steps = state.1 + 1;
+
+                for action in actions.iter() {
+                    queue.push_front((state.0 ^ *action, steps));
+                }
+
+                visited.insert(state.0);
+            }
+        }
+
+        res
+    }
+}
+
+#[cfg(feature = "z3")]
+#[solution(2025, 10, 2)]
+mod s {
+    use z3::{
+        Optimize,
+        ast::{Ast, Int},
+    };
+
+    fn solve(s: &str) -> u64 {
+        let mut res = 0;
+
+        for line in s.lines() {
+            let optimize = Optimize::new();
+
+            let targets = line
+                .split_whitespace()
+                .last()
+                .unwrap()
+                .trim_matches(|c| c == '{' || c == '}')
+                .split(',')
+                .map(|s| s.parse::<u64>().unwrap())
+                .map(Int::from_u64)
+                .collect::<Box<[Int]>>();
+
+            let coeficients = line
+                .split_whitespace()
+                .skip(1)
+                .take_while(|&s| s.starts_with('('))
+                .map(|section| {
+                    section
+                        .trim_matches(|c| c == '(' || c == ')')
+                        .split(',')
+                        .map(|s| s.parse::<usize>().unwrap())
+                        .collect::<Box<_>>()
+                })
+                .collect::<Vec<Box<[usize]>>>();
+
+            let coeficients = coeficients
+                .into_iter()
+                .map(|indexes| {
+                    let mut expanded = vec![Int::from_u64(0u64); targets.len()].into_boxed_slice();
+                    for ind in indexes {
+                        expanded[ind] = Int::from_u64(1);
+                    }
+                    expanded
+                })
+                .collect::<Vec<_>>();
+
+            let variables = (0..coeficients.len())
+                .map(|i| Int::new_const(format!("n_{i}")))
+                .collect::<Vec<_>>();
+
+            for var in &variables {
+                optimize.assert(&var.ge(Int::from_u64(0)));
+            }
+
+            let equations = coeficients
+                .into_iter()
+                .zip(&variables)
+                .map(|(coef, var)| coef.into_iter().map(|c| c * var).collect::<Vec<_>>())
+                .fold(vec![Int::from_u64(0); targets.len()], |acc, term| {
+                    acc.into_iter()
+                        .zip(term)
+                        .map(|(acc, term)| acc + term)
+                        .collect()
+                });
+
+            let equations = equations
+                .into_iter()
+                .zip(targets)
+                .map(|(equations, target)| equations.eq(&target))
+                .collect::<Vec<_>>();
+
+            for equation in &equations {
+                optimize.assert(equation);
+            }
+
+            let sum_vars = variables
+                .into_iter()
+                .fold(Int::from_u64(0), |acc, var| acc + var)
+                .simplify();
+
+            optimize.minimize(&sum_vars);
+
+            assert!(matches!(optimize.check(&[]), z3::SatResult::Sat));
+
+            let model = optimize.get_model().unwrap();
+
+            let sum_vars = model.eval(&sum_vars, true).unwrap();
+
+            res += sum_vars.as_u64().unwrap();
+        }
+
+        res
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(any(feature = "petgraph", feature = "pathfinding"))]
+use crate::solution;
+
+#[cfg(feature = "petgraph")]
+#[solution(2025, 11, 1)]
+mod s {
+    use ahash::{HashMap, HashMapExt};
+    use petgraph::{algo::all_simple_paths, graph::DiGraph};
+    use std::hash::RandomState;
+
+    fn solve(s: &str) -> usize {
+        let mut graph = DiGraph::<&str, i32>::new();
+
+        let mut names = HashMap::new();
+
+        for line in s.lines() {
+            let mut tokens = line.split_whitespace();
+
+            let origin = tokens.next().unwrap().trim_matches(|c| c == ':');
+
+            let origin = *names
+                .entry(origin)
+                .or_insert_with(|| graph.add_node(origin));
+
+            for target in tokens {
+                let target = names
+                    .entry(target)
+                    .or_insert_with(|| graph.add_node(target));
+                graph.add_edge(origin, *target, 1);
+            }
+        }
+
+        let you = names.get("you").unwrap();
+        let out = names.get("out").unwrap();
+
+        all_simple_paths::<Box<_>, _, RandomState>(&graph, *you, *out, 0, None).count()
+    }
+}
+
+#[cfg(feature = "pathfinding")]
+#[solution(2025, 11, 2)]
+mod s {
+    use ahash::{HashMap, HashMapExt};
+    use pathfinding::prelude::count_paths;
+
+    fn encode(str: &str) -> u16 {
+        str.as_bytes().iter().fold(0, |acc, b| {
+            acc * (b'z' - b'a' + 1) as u16 + (*b as u16 - b'a' as u16)
+        })
+    }
+
+    fn solve(s: &str) -> usize {
+        let mut adj = HashMap::new();
+
+        for line in s.lines() {
+            let mut tokens = line.split_whitespace();
+
+            let origin = encode(tokens.next().unwrap().trim_matches(|c| c == ':'));
+            let targets = tokens.map(encode).collect::<Box<_>>();
+
+            adj.insert(origin, targets);
+        }
+
+        let svr = encode("svr");
+        let dac = encode("dac");
+        let fft = encode("fft");
+        let out = encode("out");
+
+        let empty = vec![].into_boxed_slice();
+
+        let svr_dac = count_paths(&svr, |&n| adj.get(n).unwrap_or(&empty), |&n| *n == dac);
+        let dac_fft = count_paths(&dac, |&n| adj.get(n).unwrap_or(&empty), |&n| *n == fft);
+        let fft_out = count_paths(&fft, |&n| adj.get(n).unwrap_or(&empty), |&n| *n == out);
+
+        let svr_fft = count_paths(&svr, |&n| adj.get(n).unwrap_or(&empty), |&n| *n == fft);
+        let fft_dac = count_paths(&fft, |&n| adj.get(n).unwrap_or(&empty), |&n| *n == dac);
+        let dac_out = count_paths(&dac, |&n| adj.get(n).unwrap_or(&empty), |&n| *n == out);
+
+        svr_fft * fft_dac * dac_out + svr_dac * dac_fft * fft_out
+    }
+}
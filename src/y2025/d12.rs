@@ -0,0 +1,57 @@
+use crate::solution;
+
+#[solution(2025, 12, 1)]
+mod s {
+    type Shape = [[bool; 3]; 3];
+
+    fn solve(s: &str) -> usize {
+        let mut solvable = 0;
+
+        let mut blocks = s.split("\n\n");
+
+        let mut shapes: Box<[Shape]> = Box::new([[[false; 3]; 3]; 6]);
+        let mut areas: Box<[u8]> = Box::new([0; 6]);
+
+        for i in 0..=5 {
+            let shape = blocks.next().unwrap().lines().skip(1);
+            for (y, line) in shape.enumerate() {
+                for (x, char) in line.chars().enumerate() {
+                    if char == '#' {
+                        shapes[i][y][x] = true;
+                        areas[i] += 1;
+                    }
+                }
+            }
+        }
+
+        for grid in blocks.next().unwrap().lines() {
+            let mut tokens = grid.split_whitespace();
+
+            let (width, height) = tokens
+                .next()
+                .unwrap()
+                .trim_matches(|c| c == ':')
+                .split_once('x')
+                .unwrap();
+
+            let width = width.parse::<u8>().unwrap();
+            let height = height.parse::<u8>().unwrap();
+
+            let grid_size = width as usize * height as usize;
+
+            let shapes_count: Vec<u8> = tokens.map(|s| s.parse::<u8>().unwrap()).collect();
+
+            let shapes_area: usize = shapes_count
+                .iter()
+                .enumerate()
+                .map(|(i, &count)| count as usize * areas[i] as usize)
+                .sum();
+
+            if grid_size >= shapes_area {
+                solvable += 1;
+            }
+        }
+
+        solvable
+    }
+}
@@ -0,0 +1,19 @@
+use crate::solution;
+
+#[solution({{year}}, {{day}}, 1)]
+mod s {
+    use crate::SolveError;
+
+    fn solve(_input: &str) -> Result<u64, SolveError> {
+        Err(SolveError::new("not solved yet"))
+    }
+}
+
+#[solution({{year}}, {{day}}, 2)]
+mod s {
+    use crate::SolveError;
+
+    fn solve(_input: &str) -> Result<u64, SolveError> {
+        Err(SolveError::new("not solved yet"))
+    }
+}
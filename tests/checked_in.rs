@@ -0,0 +1,36 @@
+//! The checked-in `aoc.db`, which is public.
+
+use std::path::Path;
+
+use aoc_runner::{DbConfig, is_encrypted};
+
+#[tokio::test]
+async fn checked_in_inputs_are_encrypted() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc.db");
+    let db = DbConfig::Local(path).open().await.unwrap();
+    let conn = db.connect().unwrap();
+    let mut rows = conn
+        .query(
+            "SELECT profile, year, day, input FROM inputs WHERE input != ''",
+            (),
+        )
+        .await
+        .unwrap();
+
+    let mut plain = Vec::new();
+    while let Some(row) = rows.next().await.unwrap() {
+        if !is_encrypted(&row.get::<String>(3).unwrap()) {
+            let (profile, year, day): (String, u32, u32) = (
+                row.get(0).unwrap(),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+            );
+            plain.push(format!("{year}-{day:02}, profile {profile:?}"));
+        }
+    }
+    assert!(
+        plain.is_empty(),
+        "plain inputs in the checked-in aoc.db, run `db encrypt` on it: {}",
+        plain.join("; ")
+    );
+}
@@ -0,0 +1,95 @@
+//! A stand-in for a server on a local port, temporary databases, and the CLI run on them.
+
+// Each test file uses its own part of this
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+use std::thread;
+
+/// A request as the stand-in received it
+pub struct Request {
+    /// Request line and headers
+    pub head: Vec<String>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn has_header(&self, header: &str) -> bool {
+        self.head
+            .iter()
+            .any(|line| line.eq_ignore_ascii_case(header))
+    }
+}
+
+/// Serves one request with `status` and `body`, returning the base URL and the request once served.
+pub fn serve_once(status: u16, body: &'static str) -> (String, thread::JoinHandle<Request>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(&stream);
+        let mut head = Vec::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let line = line.trim_end().to_string();
+            if line.is_empty() {
+                break;
+            }
+            head.push(line);
+        }
+        let length = head
+            .iter()
+            .find_map(|line| {
+                line.to_ascii_lowercase()
+                    .strip_prefix("content-length:")
+                    .map(|n| n.trim().to_string())
+            })
+            .map_or(0, |n| n.parse().unwrap());
+        let mut request_body = vec![0; length];
+        reader.read_exact(&mut request_body).unwrap();
+
+        let headers = format!("Content-Length: {}\r\nConnection: close", body.len());
+        let response = format!("HTTP/1.1 {status} X\r\n{headers}\r\n\r\n{body}");
+        stream.write_all(response.as_bytes()).unwrap();
+        Request {
+            head,
+            body: String::from_utf8(request_body).unwrap(),
+        }
+    });
+    (base_url, handle)
+}
+
+/// A fresh database file for one test.
+pub fn temp_db(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("aoc-{name}-{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+/// Runs the CLI on `db` against the site at `base_url`, with the session cookie `cafe`, and `beef`
+/// for the profile `alice`. The requests it logs and the runs it records go to a database of runs
+/// in memory, so that tests neither throttle each other nor fill the crate's `runs.db`.
+pub fn cli(db: &PathBuf, base_url: &str, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc-runner"))
+        .arg("--db")
+        .arg(db)
+        .args(args)
+        .env("AOC_RUNS_DB", ":memory:")
+        .env("AOC_BASE_URL", base_url)
+        .env("AOC_SESSION", "cafe")
+        .env("AOC_SESSION_ALICE", "beef")
+        .output()
+        .unwrap()
+}
+
+pub fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+pub fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
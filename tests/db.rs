@@ -0,0 +1,62 @@
+//! `DbConfig` on the kinds of database that are not a file.
+
+mod common;
+
+use aoc_runner::{DbConfig, MIGRATIONS, migrate, schema_version};
+
+use common::serve_once;
+
+#[tokio::test]
+async fn memory_connections_start_empty() {
+    let db = DbConfig::Memory.open().await.unwrap();
+    let conn = db.connect().unwrap();
+    assert_eq!(migrate(&conn).await.unwrap().len(), MIGRATIONS.len());
+    conn.execute(
+        "INSERT INTO inputs (year, day, input) VALUES (2025, 1, 'L3')",
+        (),
+    )
+    .await
+    .unwrap();
+    let mut rows = conn.query("SELECT input FROM inputs", ()).await.unwrap();
+    let row = rows.next().await.unwrap().unwrap();
+    assert_eq!(row.get::<String>(0).unwrap(), "L3");
+
+    let other = db.connect().unwrap();
+    assert_eq!(schema_version(&other).await.unwrap(), 0);
+}
+
+/// A Hrana cursor over one row, as sqld streams it back
+const CURSOR: &str = concat!(
+    r#"{"baton":null,"base_url":null}"#,
+    "\n",
+    r#"{"type":"step_begin","step":0,"cols":[{"name":"input","decltype":"TEXT"}]}"#,
+    "\n",
+    r#"{"type":"row","row":[{"type":"text","value":"L3"}]}"#,
+    "\n",
+    r#"{"type":"step_end","affected_row_count":0,"last_insert_rowid":null}"#,
+    "\n",
+);
+
+#[tokio::test]
+async fn remote_queries_the_server_with_the_token() {
+    let (url, request) = serve_once(200, CURSOR);
+    let db = DbConfig::Remote {
+        url,
+        auth_token: "cafe".to_string(),
+    }
+    .open()
+    .await
+    .unwrap();
+    let conn = db.connect().unwrap();
+
+    let sql = "SELECT input FROM inputs WHERE year = 2025 AND day = 1";
+    let mut rows = conn.query(sql, ()).await.unwrap();
+    let row = rows.next().await.unwrap().unwrap();
+    assert_eq!(row.get::<String>(0).unwrap(), "L3");
+    assert!(rows.next().await.unwrap().is_none());
+
+    let request = request.join().unwrap();
+    assert_eq!(request.head[0], "POST /v3/cursor HTTP/1.1");
+    assert!(request.has_header("authorization: Bearer cafe"));
+    assert!(request.body.contains(sql), "{}", request.body);
+}
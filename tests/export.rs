@@ -0,0 +1,71 @@
+//! `db export` and `db import` of temporary databases.
+
+mod common;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use common::{cli, stderr, stdout, temp_db};
+
+/// Nothing here talks to the site
+const NO_SITE: &str = "http://127.0.0.1:9";
+
+fn db(db: &PathBuf, args: &[&str]) -> String {
+    let output = cli(db, NO_SITE, &[&["db"], args].concat());
+    assert!(output.status.success(), "{args:?}: {}", stderr(&output));
+    stdout(&output)
+}
+
+fn read(dir: &Path, file: &str) -> String {
+    fs::read_to_string(dir.join("2025/05").join(file)).unwrap()
+}
+
+#[test]
+fn import_restores_an_export() {
+    let (source, target) = (temp_db("export-source"), temp_db("export-target"));
+    let dir = std::env::temp_dir().join(format!("aoc-export-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let dir_arg = dir.to_str().unwrap();
+
+    #[rustfmt::skip]
+    let upserts: &[&[&str]] = &[
+        &["input", "2025", "5", "--value", "3-5\n10-14\n\n1\n5\n"],
+        &["output", "2025", "5", "1", "--value", "1"],
+        &["example", "2025", "5", "1", "--output", "1", "--label", "a/b", "--value", "1-2\n\n2\n"],
+        &["guess", "2025", "5", "1", "2", "too-high"],
+        &["puzzle", "2025", "5", "--title", "Cafeteria", "--tag", "ranges"],
+        &["completed", "2025", "5", "1", "--at", "2025-12-05 06:00:00"],
+    ];
+    for upsert in upserts {
+        db(&source, &[&["upsert"], *upsert].concat());
+    }
+    db(&source, &["export", dir_arg]);
+
+    // An earlier guess, and an answer file saved by an editor that ends it with a newline
+    let day_toml = read(&dir, "day.toml");
+    let guessed_at = day_toml
+        .lines()
+        .skip_while(|line| *line != "[[guesses]]")
+        .find_map(|line| line.strip_prefix("at = "))
+        .unwrap()
+        .to_string();
+    let day_toml = day_toml.replace(&guessed_at, "\"2025-12-05 05:30:00\"");
+    fs::write(dir.join("2025/05/day.toml"), &day_toml).unwrap();
+    fs::write(dir.join("2025/05/p1.answer"), "1\n").unwrap();
+
+    db(&target, &["import", dir_arg]);
+    fs::remove_dir_all(&dir).unwrap();
+    db(&target, &["export", dir_arg]);
+
+    assert_eq!(read(&dir, "day.toml"), day_toml);
+    assert_eq!(read(&dir, "input.txt"), "3-5\n10-14\n\n1\n5\n");
+    assert_eq!(read(&dir, "p1.answer"), "1");
+    assert_eq!(read(&dir, "example-p1-a_b.txt"), "1-2\n\n2\n");
+    assert!(
+        db(&target, &["list", "guesses"]).contains("2025\t5\t1\t2\ttoo_high\t2025-12-05 05:30:00")
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+    fs::remove_file(source).unwrap();
+    fs::remove_file(target).unwrap();
+}
@@ -0,0 +1,86 @@
+//! `fetch` against a stand-in for the site.
+
+mod common;
+
+use common::{cli, serve_once, stderr, stdout, temp_db};
+
+#[test]
+fn fetch_stores_the_input() {
+    let db = temp_db("fetch-stores");
+    let (base_url, server) = serve_once(200, "1\n2\n3\n");
+
+    let output = cli(&db, &base_url, &["fetch", "2015", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let request = server.join().unwrap();
+    assert_eq!(request.head[0], "GET /2015/day/1/input HTTP/1.1");
+    assert!(
+        request.has_header("cookie: session=cafe"),
+        "{:?}",
+        request.head
+    );
+
+    let output = cli(&db, &base_url, &["db", "read", "input", "2015", "1"]);
+    assert_eq!(stdout(&output), "1\n2\n3\n");
+
+    // Stored inputs are not downloaded again, so nothing is listening any more
+    let output = cli(&db, &base_url, &["fetch", "2015", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    std::fs::remove_file(db).unwrap();
+}
+
+#[test]
+fn fetch_reports_a_rejected_session() {
+    let db = temp_db("fetch-rejected");
+    let (base_url, server) = serve_once(
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+    );
+
+    let output = cli(&db, &base_url, &["fetch", "2015", "2"]);
+    server.join().unwrap();
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("failed with 400: Puzzle inputs differ by user."));
+    std::fs::remove_file(db).unwrap();
+}
+
+#[test]
+fn fetch_waits_for_the_release() {
+    let db = temp_db("fetch-release");
+
+    // No server: the request must not be made at all
+    let output = cli(&db, "http://127.0.0.1:9", &["fetch", "2999", "1"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("is not out until 2999-12-01 05:00:00 UTC"));
+    std::fs::remove_file(db).unwrap();
+}
+
+#[test]
+fn fetch_sends_the_session_of_the_profile() {
+    let db = temp_db("fetch-profile");
+    let (base_url, server) = serve_once(200, "4\n5\n");
+
+    let output = cli(
+        &db,
+        &base_url,
+        &["fetch", "2015", "1", "--profile", "alice"],
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let request = server.join().unwrap();
+    assert!(
+        request.has_header("cookie: session=beef"),
+        "{:?}",
+        request.head
+    );
+
+    // `AOC_SESSION` belongs to the default profile only
+    let output = cli(
+        &db,
+        "http://127.0.0.1:9",
+        &["fetch", "2015", "1", "--profile", "bob"],
+    );
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("No session cookie for profile bob, set AOC_SESSION_BOB"));
+    std::fs::remove_file(db).unwrap();
+}
@@ -0,0 +1,87 @@
+//! `run_isolated` on children of this test binary that misbehave on purpose.
+
+use std::process::Command;
+use std::time::Duration;
+
+use aoc_runner::{ISOLATED_ENV, Limits, Outcome, run_isolated};
+
+/// Runs the ignored test `name` of this binary as the child, within `limits`.
+fn run_child(name: &str, limits: Limits) -> Outcome {
+    let mut command = Command::new(std::env::current_exe().unwrap());
+    command
+        .args([name, "--exact", "--nocapture", "--include-ignored"])
+        .env(ISOLATED_ENV, "1");
+    run_isolated(command, &limits).unwrap()
+}
+
+/// Whether this is the child, rather than a run that merely includes the ignored tests.
+fn in_child() -> bool {
+    std::env::var_os(ISOLATED_ENV).is_some()
+}
+
+#[test]
+#[ignore = "child of isolated_sleep_times_out"]
+fn child_sleeps() {
+    if in_child() {
+        std::thread::sleep(Duration::from_secs(60));
+    }
+}
+
+#[test]
+#[ignore = "child of isolated_allocation_runs_out_of_memory"]
+fn child_allocates() {
+    if in_child() {
+        let heap = vec![1u8; 1 << 30];
+        std::hint::black_box(heap);
+    }
+}
+
+#[test]
+#[ignore = "child of isolated_panic_is_reported"]
+fn child_panics() {
+    if in_child() {
+        panic!("the child gave up");
+    }
+}
+
+#[test]
+fn isolated_sleep_times_out() {
+    let timeout = Duration::from_secs(1);
+    let limits = Limits {
+        timeout: Some(timeout),
+        memory: None,
+    };
+    assert_eq!(
+        run_child("child_sleeps", limits),
+        Outcome::TimedOut(timeout)
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn isolated_allocation_runs_out_of_memory() {
+    let limit = 64 << 20;
+    let limits = Limits {
+        timeout: Some(Duration::from_secs(60)),
+        memory: Some(limit),
+    };
+    assert_eq!(
+        run_child("child_allocates", limits),
+        Outcome::OutOfMemory(limit)
+    );
+}
+
+#[test]
+fn isolated_panic_is_reported() {
+    let limits = Limits {
+        timeout: Some(Duration::from_secs(60)),
+        memory: None,
+    };
+    match run_child("child_panics", limits) {
+        Outcome::Panicked(message) => {
+            assert!(message.starts_with("the child gave up\n  at "), "{message}");
+            assert!(message.contains("tests/isolate.rs"), "{message}");
+        }
+        outcome => panic!("expected a panic, got {outcome:?}"),
+    }
+}
@@ -0,0 +1,218 @@
+//! Migrations of temporary databases with the schema of an older release.
+
+mod common;
+
+use std::path::Path;
+
+use aoc_runner::{DbConfig, MIGRATIONS, migrate, schema_version};
+use libsql::Connection;
+
+use common::temp_db;
+
+/// The schemas before `schema_version`, each applied on top of the previous one
+const LEGACY: &[&str] = &[
+    include_str!("../src/migrations/0001_solutions.sql"),
+    include_str!("../src/migrations/0002_examples.sql"),
+    include_str!("../src/migrations/0003_params.sql"),
+    include_str!("../src/migrations/0004_profiles.sql"),
+    include_str!("../src/migrations/0005_inputs_and_answers.sql"),
+];
+
+async fn connect(path: &Path) -> Connection {
+    let db = DbConfig::Local(path.to_path_buf()).open().await.unwrap();
+    db.connect().unwrap()
+}
+
+/// A database with the schema of `version`, from before `schema_version`.
+async fn legacy_db(name: &str, version: usize) -> (std::path::PathBuf, Connection) {
+    let path = temp_db(name);
+    let conn = connect(&path).await;
+    for sql in &LEGACY[..version] {
+        conn.execute_batch(sql).await.unwrap();
+    }
+    (path, conn)
+}
+
+/// Every row of `sql`, with its columns joined by `|`.
+async fn rows(conn: &Connection, sql: &str) -> Vec<String> {
+    let mut rows = conn.query(sql, ()).await.unwrap();
+    let mut all = Vec::new();
+    while let Some(row) = rows.next().await.unwrap() {
+        let columns = (0..row.column_count())
+            .map(|i| match row.get_value(i).unwrap() {
+                libsql::Value::Integer(n) => n.to_string(),
+                libsql::Value::Text(text) => text,
+                value => format!("{value:?}"),
+            })
+            .collect::<Vec<_>>();
+        all.push(columns.join("|"));
+    }
+    all
+}
+
+/// The schema and every row of the database.
+async fn dump(conn: &Connection) -> Vec<String> {
+    let schema = "SELECT type, name, COALESCE(sql, '') FROM sqlite_master ORDER BY name";
+    let mut dump = rows(conn, schema).await;
+    for table in rows(conn, "SELECT name FROM sqlite_master WHERE type = 'table'").await {
+        dump.push(format!("{table}:"));
+        dump.extend(rows(conn, &format!("SELECT * FROM {table}")).await);
+    }
+    dump
+}
+
+/// The parameters of a day after the migration, in order.
+async fn params(conn: &Connection, profile: &str, day: u32) -> Vec<String> {
+    let sql = format!("SELECT params FROM inputs WHERE profile = '{profile}' AND day = {day}");
+    let mut params: Vec<String> = rows(conn, &sql).await[0]
+        .split(',')
+        .filter(|pair| !pair.is_empty())
+        .map(str::to_string)
+        .collect();
+    params.sort();
+    params
+}
+
+/// Parts of two days and two profiles, as stored before inputs and answers were split
+const SOLUTIONS_V4: &str = "
+    INSERT INTO solutions (profile, year, day, part, input, output, params) VALUES
+        ('default', 2025, 1, 1, 'L3', '1086', ''),
+        ('default', 2025, 1, 2, 'L3', '', ''),
+        ('default', 2025, 6, 1, '1 2', '3', 'rows=4'),
+        ('alice', 2025, 6, 2, '3 4', '7', 'rows=2'),
+        ('default', 2025, 6, 2, '1 2', '2', ' cols = 3,rows=4');
+";
+
+#[tokio::test]
+async fn legacy_versions_are_detected() {
+    let path = temp_db("migrations-legacy");
+    let conn = connect(&path).await;
+    assert_eq!(schema_version(&conn).await.unwrap(), 0);
+    for (version, sql) in LEGACY.iter().enumerate() {
+        conn.execute_batch(sql).await.unwrap();
+        assert_eq!(schema_version(&conn).await.unwrap(), version as u32 + 1);
+    }
+
+    let applied = migrate(&conn).await.unwrap();
+    let applied: Vec<u32> = applied.iter().map(|m| m.version).collect();
+    assert_eq!(applied, (6..=MIGRATIONS.len() as u32).collect::<Vec<_>>());
+    let recorded = rows(&conn, "SELECT version, name FROM schema_version").await;
+    let expected: Vec<String> = MIGRATIONS
+        .iter()
+        .map(|m| format!("{}|{}", m.version, m.name))
+        .collect();
+    assert_eq!(recorded, expected);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn baseline_is_migrated() {
+    let (path, conn) = legacy_db("migrations-baseline", 1).await;
+    conn.execute(
+        "INSERT INTO solutions (year, day, part, input, output) VALUES (2025, 1, 1, 'L3', '1086')",
+        (),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(migrate(&conn).await.unwrap().len(), MIGRATIONS.len() - 1);
+    assert_eq!(
+        rows(&conn, "SELECT * FROM inputs").await,
+        ["default|2025|1|L3|"]
+    );
+    assert_eq!(
+        rows(&conn, "SELECT * FROM answers").await,
+        ["default|2025|1|1|1086"]
+    );
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn params_of_the_parts_are_merged() {
+    let (path, conn) = legacy_db("migrations-params", 4).await;
+    conn.execute_batch(SOLUTIONS_V4).await.unwrap();
+
+    migrate(&conn).await.unwrap();
+    let inputs = rows(
+        &conn,
+        "SELECT profile, year, day, input FROM inputs ORDER BY 1, 2, 3",
+    )
+    .await;
+    assert_eq!(
+        inputs,
+        [
+            "alice|2025|6|3 4",
+            "default|2025|1|L3",
+            "default|2025|6|1 2"
+        ]
+    );
+    assert_eq!(params(&conn, "default", 6).await, ["cols = 3", "rows=4"]);
+    assert_eq!(params(&conn, "alice", 6).await, ["rows=2"]);
+    assert!(params(&conn, "default", 1).await.is_empty());
+    assert_eq!(
+        rows(&conn, "SELECT * FROM answers ORDER BY 1, 2, 3, 4").await,
+        [
+            "alice|2025|6|2|7",
+            "default|2025|1|1|1086",
+            "default|2025|6|1|3",
+            "default|2025|6|2|2"
+        ]
+    );
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn conflicting_parts_abort_and_leave_the_database_unchanged() {
+    let (path, conn) = legacy_db("migrations-conflict", 4).await;
+    conn.execute_batch(SOLUTIONS_V4).await.unwrap();
+    conn.execute_batch(
+        "UPDATE solutions SET input = 'R4' WHERE day = 1 AND part = 2;
+         UPDATE solutions SET params = 'rows=5' WHERE day = 6 AND part = 1;",
+    )
+    .await
+    .unwrap();
+    let before = dump(&conn).await;
+
+    let Err(error) = migrate(&conn).await else {
+        panic!("the migration went through");
+    };
+    let error = error.to_string();
+    assert!(error.contains("cannot apply migration 5"), "{error}");
+    assert!(
+        error.contains("profile default, 2025 day 1: the parts have different inputs"),
+        "{error}"
+    );
+    assert!(
+        error.contains("profile default, 2025 day 6: the parts set parameter 'rows' to"),
+        "{error}"
+    );
+    assert!(!error.contains("alice"), "{error}");
+    assert_eq!(dump(&conn).await, before);
+    assert_eq!(schema_version(&conn).await.unwrap(), 4);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn failed_migration_is_rolled_back() {
+    let (path, conn) = legacy_db("migrations-rollback", 4).await;
+    conn.execute_batch(SOLUTIONS_V4).await.unwrap();
+    // Migration 5 creates `inputs`, then fails on an `answers` table left over from elsewhere
+    conn.execute("CREATE TABLE answers (answer TEXT)", ())
+        .await
+        .unwrap();
+    let before = dump(&conn).await;
+
+    let Err(error) = migrate(&conn).await else {
+        panic!("the migration went through");
+    };
+    let error = error.to_string();
+    assert!(error.contains("answers already exists"), "{error}");
+    assert_eq!(dump(&conn).await, before);
+    assert_eq!(schema_version(&conn).await.unwrap(), 4);
+
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,98 @@
+//! `submit` against a stand-in for the site, answering like it does.
+
+mod common;
+
+use common::{cli, serve_once, stderr, stdout, temp_db};
+
+const RIGHT: &str = "<main><article><p>That's the right answer!  \
+    You are one gold star closer to saving Christmas.</p></article></main>";
+const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  \
+    Please wait one minute before trying again.</p></article></main>";
+const TOO_RECENT: &str = "<main><article><p>You gave an answer too recently; \
+    you have to wait after submitting an answer before trying again.  \
+    You have 38s left to wait.</p></article></main>";
+
+#[test]
+fn submit_records_a_right_answer() {
+    let db = temp_db("submit-right");
+    let (base_url, server) = serve_once(200, RIGHT);
+
+    let output = cli(&db, &base_url, &["submit", "2015", "1", "2", "1795"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let request = server.join().unwrap();
+    assert_eq!(request.head[0], "POST /2015/day/1/answer HTTP/1.1");
+    assert!(
+        request.has_header("cookie: session=cafe"),
+        "{:?}",
+        request.head
+    );
+    assert_eq!(request.body, "level=2&answer=1795");
+
+    assert_eq!(
+        stdout(&cli(
+            &db,
+            &base_url,
+            &["db", "read", "output", "2015", "1", "2"]
+        )),
+        "1795"
+    );
+    assert!(
+        stdout(&cli(&db, &base_url, &["db", "list", "guesses"]))
+            .contains("2015\t1\t2\t1795\tcorrect")
+    );
+
+    // A completed part is not submitted again, so nothing is listening any more
+    let output = cli(&db, &base_url, &["submit", "2015", "1", "2", "1795"]);
+    assert!(
+        stdout(&output).contains("Already completed"),
+        "{}",
+        stderr(&output)
+    );
+    std::fs::remove_file(db).unwrap();
+}
+
+#[test]
+fn submit_records_a_bound_and_refuses_answers_beyond_it() {
+    let db = temp_db("submit-bound");
+    let (base_url, server) = serve_once(200, TOO_HIGH);
+
+    let output = cli(&db, &base_url, &["submit", "2015", "1", "1", "500"]);
+    server.join().unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("Answer 500 is too high"),
+        "{}",
+        stderr(&output)
+    );
+    assert!(
+        stdout(&cli(&db, &base_url, &["db", "list", "guesses"]))
+            .contains("2015\t1\t1\t500\ttoo_high")
+    );
+
+    let output = cli(&db, &base_url, &["submit", "2015", "1", "1", "600"]);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("Not submitting: 600 is above 500, which is too high"),
+        "{}",
+        stderr(&output)
+    );
+    std::fs::remove_file(db).unwrap();
+}
+
+#[test]
+fn submit_reports_the_wait_when_rate_limited() {
+    let db = temp_db("submit-recent");
+    let (base_url, server) = serve_once(200, TOO_RECENT);
+
+    let output = cli(&db, &base_url, &["submit", "2015", "2", "1", "42"]);
+    server.join().unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("Answer given too recently, wait 38s"),
+        "{}",
+        stderr(&output)
+    );
+    assert_eq!(stdout(&cli(&db, &base_url, &["db", "list", "guesses"])), "");
+    std::fs::remove_file(db).unwrap();
+}
@@ -1,353 +1,972 @@
 // @generated by build.rs — do not edit
+#![cfg_attr(rustfmt, rustfmt::skip)]
 
-use aoc_runner::Solution;
-use libsql::Builder;
+use aoc_runner::{
+    DbConfig, INPUT_KEY_ENV, INPUT_KEY_FILE_ENV, InputKey, Params, Solution,
+};
+use aoc_runner::decrypt_input;
+#[allow(unused_imports)]
+use aoc_runner::isolated;
 use std::sync::LazyLock;
+/// Number of generated inputs each variant is cross-checked on
+const CROSS_CHECK_SEEDS: u64 = 200;
+#[allow(dead_code)]
 struct TestEntry {
-    input: String,
+    profile: String,
+    /// `None` when the input is encrypted and there is no key
+    input: Option<String>,
     expected: String,
-    solve: Box<dyn Fn(&str) -> String + Send + Sync>,
+    params: Params,
+    solution: &'static dyn Solution,
 }
 static ENTRIES: LazyLock<Vec<(u16, u8, u8, TestEntry)>> = LazyLock::new(|| {
     let rt = tokio::runtime::Runtime::new().unwrap();
-    let db_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc.db");
-    let db = rt.block_on(Builder::new_local(db_path).build()).unwrap();
+    let db = rt.block_on(DbConfig::resolve(None).unwrap().open()).unwrap();
+    let conn = db.connect().unwrap();
+    rt.block_on(aoc_runner::migrate(&conn)).unwrap();
+    let key = InputKey::load().unwrap_or_else(|e| panic!("{e}"));
+    let mut entries = Vec::new();
+    for solution in inventory::iter::<&dyn Solution> {
+        let year = solution.year();
+        let day = solution.day();
+        let part = solution.part();
+        let Ok(mut rows) = rt
+            .block_on(
+                conn
+                    .query(
+                        "SELECT i.profile, i.input, COALESCE(a.output, ''), i.params FROM inputs i
+                     LEFT JOIN answers a
+                       ON a.profile = i.profile AND a.year = i.year AND a.day = i.day AND a.part = ?
+                     WHERE i.year = ? AND i.day = ? ORDER BY i.profile",
+                        (part, year, day),
+                    ),
+            ) else {
+            continue;
+        };
+        while let Ok(Some(row)) = rt.block_on(rows.next()) {
+            let profile: String = row.get(0).unwrap();
+            let input: String = row.get(1).unwrap();
+            let params: String = row.get(3).unwrap();
+            entries
+                .push((
+                    year,
+                    day,
+                    part,
+                    TestEntry {
+                        input: decrypt_input(&input, key.as_ref())
+                            .unwrap_or_else(|e| {
+                                panic!("{year}-{day:02}, profile {profile:?}: {e}")
+                            }),
+                        profile,
+                        expected: row.get(2).unwrap(),
+                        params: params.parse().unwrap(),
+                        solution: *solution,
+                    },
+                ));
+        }
+    }
+    entries
+});
+struct Example {
+    year: u16,
+    day: u8,
+    part: u8,
+    label: String,
+    input: String,
+    expected: String,
+    params: Params,
+}
+static EXAMPLES: LazyLock<Vec<Example>> = LazyLock::new(|| {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let db = rt.block_on(DbConfig::resolve(None).unwrap().open()).unwrap();
     let conn = db.connect().unwrap();
+    rt.block_on(aoc_runner::migrate(&conn)).unwrap();
+    let Ok(mut rows) = rt
+        .block_on(
+            conn
+                .query(
+                    "SELECT year, day, part, label, input, output, params FROM examples
+                 WHERE input != '' AND output != ''",
+                    (),
+                ),
+        ) else {
+        return Vec::new();
+    };
+    let mut examples = Vec::new();
+    while let Ok(Some(row)) = rt.block_on(rows.next()) {
+        examples
+            .push(Example {
+                year: row.get::<u32>(0).unwrap() as u16,
+                day: row.get::<u32>(1).unwrap() as u8,
+                part: row.get::<u32>(2).unwrap() as u8,
+                label: row.get(3).unwrap(),
+                input: row.get(4).unwrap(),
+                expected: row.get(5).unwrap(),
+                params: row.get::<String>(6).unwrap().parse().unwrap(),
+            });
+    }
+    examples
+});
+fn variants_of(year: u16, day: u8, part: u8) -> Vec<&'static dyn Solution> {
     inventory::iter::<&dyn Solution>
         .into_iter()
-        .filter_map(|solution| {
-            let year = solution.year();
-            let day = solution.day();
-            let part = solution.part();
-            let mut rows = rt
-                .block_on(
-                    conn
-                        .query(
-                            "SELECT input, output FROM solutions WHERE year = ? AND day = ? AND part = ?",
-                            (year, day, part),
-                        ),
-                )
-                .ok()?;
-            let row = rt.block_on(rows.next()).ok()??;
-            let input: String = row.get(0).ok()?;
-            let expected: String = row.get(1).ok()?;
-            let s: &'static &'static dyn Solution = solution;
-            Some((
-                year,
-                day,
-                part,
-                TestEntry {
-                    input,
-                    expected,
-                    solve: Box::new(move |input: &str| s.solve(input)),
-                },
-            ))
-        })
+        .copied()
+        .filter(|s| s.year() == year && s.day() == day && s.part() == part)
         .collect()
-});
-fn find_entry(year: u16, day: u8, part: u8) -> &'static TestEntry {
-    &ENTRIES
+}
+/// Runs every variant of a puzzle on one of its examples.
+#[allow(dead_code)]
+fn check_example(year: u16, day: u8, part: u8, label: &str) {
+    let Some(example) = EXAMPLES
         .iter()
-        .find(|(y, d, p, _)| *y == year && *d == day && *p == part)
-        .unwrap_or_else(|| panic!("no entry for {year}-{day:02}-{part}"))
-        .3
+        .find(|e| e.year == year && e.day == day && e.part == part && e.label == label)
+    else {
+        panic!(
+            "year {year} day {day} part {part}: no example {label:?} in the database, \
+                     which changed since the tests were generated"
+        );
+    };
+    for solution in variants_of(year, day, part) {
+        let actual = solution
+            .try_solve_with(&example.input, &example.params)
+            .unwrap_or_else(|e| panic!("{e}"));
+        assert_eq!(
+            actual.trim(), example.expected.trim(),
+            "year {year} day {day} part {part} variant {:?}, example {label:?}: \
+                     expected '{}', got '{}'",
+            solution.variant().unwrap_or("default"), example.expected.trim(), actual
+            .trim(),
+        );
+    }
 }
+/// The stored inputs of a solution, one per profile.
+#[allow(dead_code)]
+fn find_entries(
+    year: u16,
+    day: u8,
+    part: u8,
+    variant: Option<&str>,
+) -> Vec<&'static TestEntry> {
+    let entries: Vec<_> = ENTRIES
+        .iter()
+        .filter(|(y, d, p, e)| {
+            *y == year && *d == day && *p == part && e.solution.variant() == variant
+        })
+        .map(|(_, _, _, e)| e)
+        .collect();
+    if entries.is_empty() {
+        panic!("no entry for {year}-{day:02}-{part} {variant:?}");
+    }
+    entries
+}
+/// The decrypted input of an entry, which takes the key when it is encrypted.
+#[allow(dead_code)]
+fn input_of(entry: &TestEntry) -> &str {
+    entry
+        .input
+        .as_deref()
+        .unwrap_or_else(|| {
+            panic!(
+                "profile {:?}: the input is encrypted, \
+                     set {INPUT_KEY_ENV} or {INPUT_KEY_FILE_ENV}",
+                entry.profile,
+            )
+        })
+}
+/// Runs every variant of a puzzle on the stored inputs, the examples and
+/// generated inputs, and checks that they all agree with the reference variant.
+#[allow(dead_code)]
+fn cross_check(year: u16, day: u8, part: u8) {
+    let variants = variants_of(year, day, part);
+    let reference = variants
+        .iter()
+        .find(|s| s.is_reference())
+        .or_else(|| variants.iter().find(|s| s.variant().is_none()))
+        .unwrap_or_else(|| panic!("no reference variant for {year}-{day:02}-{part}"));
+    let mut inputs: Vec<(String, String, Params)> = ENTRIES
+        .iter()
+        .filter(|(y, d, p, e)| {
+            *y == year && *d == day && *p == part
+                && e.solution.variant() == reference.variant()
+        })
+        .map(|(_, _, _, e)| {
+            let label = format!("input of profile {:?}", e.profile);
+            (label, input_of(e).to_string(), e.params.clone())
+        })
+        .filter(|(_, input, _)| !input.is_empty())
+        .collect();
+    inputs
+        .extend(
+            EXAMPLES
+                .iter()
+                .filter(|e| e.year == year && e.day == day && e.part == part)
+                .map(|e| (
+                    format!("example {:?}", e.label),
+                    e.input.clone(),
+                    e.params.clone(),
+                )),
+        );
+    inputs
+        .extend(
+            (0..CROSS_CHECK_SEEDS)
+                .filter_map(|seed| {
+                    reference
+                        .generate(seed)
+                        .map(|input| {
+                            (
+                                format!("generated input (seed {seed})"),
+                                input,
+                                Params::default(),
+                            )
+                        })
+                }),
+        );
+    for (label, input, params) in &inputs {
+        let expected = reference
+            .try_solve_with(input, params)
+            .unwrap_or_else(|e| panic!("reference failed on {label}: {e}"));
+        for variant in variants.iter().filter(|s| s.variant() != reference.variant()) {
+            let actual = variant
+                .try_solve_with(input, params)
+                .unwrap_or_else(|e| panic!("{label}: {e}"));
+            assert_eq!(
+                actual.trim(), expected.trim(),
+                "year {year} day {day} part {part}: \
+                         variant {:?} disagrees with reference {:?} on {label}",
+                variant.variant().unwrap_or("default"), reference.variant()
+                .unwrap_or("default"),
+            );
+        }
+    }
+}
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d01",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d01_p1() {
-    let entry = find_entry(2025, 1, 1);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 1, 1, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d01_p1",
+        || {
+            for entry in find_entries(2025, 1, 1, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 1 part 1", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d01",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d01_p2() {
-    let entry = find_entry(2025, 1, 2);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 1, 2, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d01_p2",
+        || {
+            for entry in find_entries(2025, 1, 2, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 1 part 2", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
+    );
+}
+#[cfg(feature = "y2025")]
+#[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d01",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
+fn y2025_d01_p2_naive() {
+    isolated(
+        "y2025_d01_p2_naive",
+        || {
+            for entry in find_entries(2025, 1, 2, Some("naive")) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 1 part 2 (naive)", entry.profile, entry.expected
+                    .trim(), actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d02",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d02_p1() {
-    let entry = find_entry(2025, 2, 1);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 2, 1, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d02_p1",
+        || {
+            for entry in find_entries(2025, 2, 1, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 2 part 1", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d02",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d02_p2() {
-    let entry = find_entry(2025, 2, 2);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 2, 2, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d02_p2",
+        || {
+            for entry in find_entries(2025, 2, 2, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 2 part 2", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d03",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d03_p1() {
-    let entry = find_entry(2025, 3, 1);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 3, 1, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d03_p1",
+        || {
+            for entry in find_entries(2025, 3, 1, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 3 part 1", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d03",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d03_p2() {
-    let entry = find_entry(2025, 3, 2);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 3, 2, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d03_p2",
+        || {
+            for entry in find_entries(2025, 3, 2, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 3 part 2", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d04",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d04_p1() {
-    let entry = find_entry(2025, 4, 1);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 4, 1, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d04_p1",
+        || {
+            for entry in find_entries(2025, 4, 1, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 4 part 1", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d04",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d04_p2() {
-    let entry = find_entry(2025, 4, 2);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 4, 2, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d04_p2",
+        || {
+            for entry in find_entries(2025, 4, 2, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 4 part 2", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d05",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d05_p1() {
-    let entry = find_entry(2025, 5, 1);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 5, 1, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d05_p1",
+        || {
+            for entry in find_entries(2025, 5, 1, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 5 part 1", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d05",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d05_p2() {
-    let entry = find_entry(2025, 5, 2);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 5, 2, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d05_p2",
+        || {
+            for entry in find_entries(2025, 5, 2, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 5 part 2", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d06",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d06_p1() {
-    let entry = find_entry(2025, 6, 1);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 6, 1, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d06_p1",
+        || {
+            for entry in find_entries(2025, 6, 1, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 6 part 1", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d06",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d06_p2() {
-    let entry = find_entry(2025, 6, 2);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 6, 2, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d06_p2",
+        || {
+            for entry in find_entries(2025, 6, 2, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 6 part 2", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d07",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d07_p1() {
-    let entry = find_entry(2025, 7, 1);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 7, 1, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d07_p1",
+        || {
+            for entry in find_entries(2025, 7, 1, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 7 part 1", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d07",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d07_p2() {
-    let entry = find_entry(2025, 7, 2);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 7, 2, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d07_p2",
+        || {
+            for entry in find_entries(2025, 7, 2, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 7 part 2", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d08",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d08_p1() {
-    let entry = find_entry(2025, 8, 1);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 8, 1, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d08_p1",
+        || {
+            for entry in find_entries(2025, 8, 1, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 8 part 1", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d08",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d08_p2() {
-    let entry = find_entry(2025, 8, 2);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 8, 2, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d08_p2",
+        || {
+            for entry in find_entries(2025, 8, 2, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 8 part 2", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d09",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d09_p1() {
-    let entry = find_entry(2025, 9, 1);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 9, 1, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d09_p1",
+        || {
+            for entry in find_entries(2025, 9, 1, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 9 part 1", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d09",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d09_p2() {
-    let entry = find_entry(2025, 9, 2);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 9, 2, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d09_p2",
+        || {
+            for entry in find_entries(2025, 9, 2, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 9 part 2", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d10",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d10_p1() {
-    let entry = find_entry(2025, 10, 1);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 10, 1, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d10_p1",
+        || {
+            for entry in find_entries(2025, 10, 1, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 10 part 1", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(all(feature = "y2025", feature = "z3"))]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d10",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d10_p2() {
-    let entry = find_entry(2025, 10, 2);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 10, 2, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d10_p2",
+        || {
+            for entry in find_entries(2025, 10, 2, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 10 part 2", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(all(feature = "y2025", feature = "petgraph"))]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d11",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d11_p1() {
-    let entry = find_entry(2025, 11, 1);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 11, 1, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d11_p1",
+        || {
+            for entry in find_entries(2025, 11, 1, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 11 part 1", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(all(feature = "y2025", feature = "pathfinding"))]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d11",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d11_p2() {
-    let entry = find_entry(2025, 11, 2);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 11, 2, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d11_p2",
+        || {
+            for entry in find_entries(2025, 11, 2, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 11 part 2", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(feature = "y2025")]
 #[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d12",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
 fn y2025_d12_p1() {
-    let entry = find_entry(2025, 12, 1);
-    if entry.input.is_empty() || entry.expected.is_empty() {
-        return;
-    }
-    let actual = (entry.solve)(&entry.input);
-    assert_eq!(
-        actual.trim(), entry.expected.trim(),
-        "year {} day {} part {}: expected '{}', got '{}'", 2025, 12, 1, entry.expected
-        .trim(), actual.trim(),
+    isolated(
+        "y2025_d12_p1",
+        || {
+            for entry in find_entries(2025, 12, 1, None) {
+                let input = input_of(entry);
+                if input.is_empty() || entry.expected.is_empty() {
+                    continue;
+                }
+                let actual = entry
+                    .solution
+                    .try_solve_with(input, &entry.params)
+                    .unwrap_or_else(|e| panic!("profile {:?}: {e}", entry.profile));
+                assert_eq!(
+                    actual.trim(), entry.expected.trim(),
+                    "{}, profile {:?}: expected '{}', got '{}'",
+                    "year 2025 day 12 part 1", entry.profile, entry.expected.trim(),
+                    actual.trim(),
+                );
+            }
+        },
     );
 }
+#[cfg(any(feature = "y2025", feature = "y2025"))]
+#[test]
+#[cfg_attr(
+    aoc_locked_input = "y2025_d01",
+    ignore = "encrypted input, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE"
+)]
+#[cfg_attr(aoc_memory_db, ignore = "AOC_DB is :memory:, which holds no inputs")]
+fn y2025_d01_p2_cross_check() {
+    isolated("y2025_d01_p2_cross_check", || cross_check(2025, 1, 2));
+}
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));